@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scrabby::{computer, letter::RackLetter, Board, Direction, Position, Word};
+
+fn rack(letters: &str) -> Vec<RackLetter> {
+    letters.chars().map(RackLetter::from_char).collect()
+}
+
+fn init_board() -> Board {
+    let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+    b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+    b.make_move(Position::new(b.size(), 10, 10), "RADICAL", Direction::Down);
+    b
+}
+
+fn complex_board() -> Board {
+    let mut b = init_board();
+    b.make_move(Position::new(b.size(), 16, 6), "CAROL", Direction::Right);
+    b.make_move(Position::new(b.size(), 6, 13), "BOAST", Direction::Down);
+    b.make_move(Position::new(b.size(), 13, 7), "PLAID", Direction::Right);
+    b
+}
+
+/// 55 tiles, from playing the best move with a fixed sequence of racks
+fn late_game_board() -> Board {
+    let mut b = init_board();
+    b.make_move(Position::new(b.size(), 13, 6), "RAINIEST", Direction::Right);
+    b.make_move(Position::new(b.size(), 12, 5), "ROMPED", Direction::Right);
+    b.make_move(Position::new(b.size(), 11, 5), "ARGENT", Direction::Down);
+    b.make_move(Position::new(b.size(), 14, 1), "LINSEED", Direction::Right);
+    b.make_move(Position::new(b.size(), 8, 2), "HIERATIC", Direction::Down);
+    b.make_move(Position::new(b.size(), 10, 0), "BEER", Direction::Right);
+    b.make_move(Position::new(b.size(), 8, 1), "STEAK", Direction::Down);
+    b.make_move(Position::new(b.size(), 15, 2), "CAIN", Direction::Right);
+    b.make_move(Position::new(b.size(), 6, 13), "REOUTFIT", Direction::Down);
+    b
+}
+
+fn bench_best_moves(c: &mut Criterion, name: &str, b: &Board) {
+    let rack = rack("AEINRST");
+    c.bench_function(name, |bench| {
+        bench.iter(|| computer::best_moves(black_box(b), &rack, scrabby::DEFAULT_WORD_LIST).count())
+    });
+}
+
+fn bench_best_moves_empty_board(c: &mut Criterion) {
+    bench_best_moves(
+        c,
+        "best_moves_empty_board",
+        &Board::new(Board::DEFAULT_SS_BOARD_SIZE),
+    );
+}
+
+fn bench_best_moves_midgame(c: &mut Criterion) {
+    bench_best_moves(c, "best_moves_midgame", &init_board());
+}
+
+fn bench_best_moves_complex(c: &mut Criterion) {
+    bench_best_moves(c, "best_moves_complex", &complex_board());
+}
+
+fn bench_best_moves_late_game(c: &mut Criterion) {
+    bench_best_moves(c, "best_moves_late_game", &late_game_board());
+}
+
+/// A rack with no legal move on the late game board, so the whole search has to run
+fn bench_blocked(c: &mut Criterion) {
+    let b = late_game_board();
+    let rack = rack("QQQQQQQ");
+    assert!(!computer::has_any_legal_move(
+        &b,
+        &rack,
+        scrabby::DEFAULT_WORD_LIST
+    ));
+    c.bench_function("has_any_legal_move_blocked", |bench| {
+        bench
+            .iter(|| computer::has_any_legal_move(black_box(&b), &rack, scrabby::DEFAULT_WORD_LIST))
+    });
+    c.bench_function("best_moves_blocked", |bench| {
+        bench.iter(|| {
+            computer::best_moves(black_box(&b), &rack, scrabby::DEFAULT_WORD_LIST)
+                .next()
+                .is_some()
+        })
+    });
+}
+
+fn bench_verify_move(c: &mut Criterion, name: &str, word: Word, valid: bool) {
+    let b = init_board();
+    assert_eq!(
+        computer::verify_move(&b, &word, scrabby::DEFAULT_WORD_LIST),
+        valid
+    );
+    c.bench_function(name, |bench| {
+        bench.iter(|| computer::verify_move(&b, black_box(&word), scrabby::DEFAULT_WORD_LIST))
+    });
+}
+
+fn bench_verify_move_valid(c: &mut Criterion) {
+    let word = Word::new(
+        Position::new(Board::DEFAULT_SS_BOARD_SIZE, 10, 10),
+        Direction::Right,
+        Cow::Borrowed("RUSTED"),
+    );
+    bench_verify_move(c, "verify_move_valid", word, true);
+}
+
+fn bench_verify_move_invalid(c: &mut Criterion) {
+    let word = Word::new(
+        Position::new(Board::DEFAULT_SS_BOARD_SIZE, 11, 9),
+        Direction::Right,
+        Cow::Borrowed("RUST"),
+    );
+    bench_verify_move(c, "verify_move_invalid", word, false);
+}
+
+fn bench_can_create_word(c: &mut Criterion) {
+    let rack = rack("AEINRS ");
+    c.bench_function("can_create_word", |bench| {
+        bench.iter(|| {
+            scrabby::DEFAULT_WORD_LIST
+                .iter()
+                .filter(|word| computer::can_create_word(black_box(&rack), word))
+                .count()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_best_moves_empty_board,
+    bench_best_moves_midgame,
+    bench_best_moves_complex,
+    bench_best_moves_late_game,
+    bench_blocked,
+    bench_verify_move_valid,
+    bench_verify_move_invalid,
+    bench_can_create_word
+);
+criterion_main!(benches);
@@ -1,14 +1,46 @@
 pub fn main() {
+    println!("cargo:rerun-if-env-changed=SCRABBY_WORD_LIST_DIR");
+
+    // Either every `.txt` file in `SCRABBY_WORD_LIST_DIR`, or the bundled `words.txt`
+    let files = match std::env::var("SCRABBY_WORD_LIST_DIR") {
+        Ok(dir) => {
+            println!("cargo:rerun-if-changed={dir}");
+            let mut files = std::fs::read_dir(&dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect::<Vec<_>>();
+            files.sort();
+            files
+        }
+        Err(_) => {
+            println!("cargo:rerun-if-changed=words.txt");
+            vec![std::path::PathBuf::from("words.txt")]
+        }
+    };
+
+    // Sort the list so that nothing downstream depends on the line order of the files,
+    // and uppercase it so that words are deduplicated case-insensitively. Only words made
+    // up of the letters A to Z can be played, so anything else is left out
+    let mut words = files
+        .iter()
+        .flat_map(|file| {
+            std::fs::read_to_string(file)
+                .unwrap()
+                .split('\n')
+                .map(|word| word.trim().to_ascii_uppercase())
+                .filter(|word| !word.is_empty() && word.chars().all(|ch| ch.is_ascii_alphabetic()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    words.sort_unstable();
+    words.dedup();
+
     std::fs::write(
         std::env::var("OUT_DIR").unwrap() + "/words.rs",
         [String::from("[")]
             .into_iter()
-            .chain(
-                std::fs::read_to_string("words.txt")
-                    .unwrap()
-                    .split('\n')
-                    .map(|word| format!("\"{word}\",")),
-            )
+            .chain(words.into_iter().map(|word| format!("{word:?},")))
             .chain([String::from("]")])
             .collect::<String>(),
     )
@@ -0,0 +1,43 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scrabby::{computer, Board, BoardLayout, Letter, Position, Word};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Action {
+    Move(Word),
+    Set(Position, Option<Letter>),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input {
+    size: u8,
+    layout: Option<BoardLayout>,
+    actions: Vec<Action>,
+}
+
+fuzz_target!(|input: Input| {
+    // Keep the board small enough to not run out of memory
+    let size = input.size as usize % 32;
+    let mut board = match input.layout {
+        Some(layout) => match Board::new_from_size_and_layout(size, layout) {
+            Ok(board) => board,
+            Err(_) => return,
+        },
+        None => Board::new(size),
+    };
+
+    for action in input.actions {
+        match action {
+            Action::Move(word) => {
+                if computer::verify_move(&board, &word, scrabby::DEFAULT_WORD_LIST) {
+                    word.get_score(&board, None);
+                    board.make_move(word.position, &word.word, word.direction);
+                }
+            }
+            Action::Set(position, letter) => {
+                let _ = board.set_with_validation(position, letter);
+            }
+        }
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scrabby::{computer, letter::TileRack};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input {
+    rack: TileRack,
+    word: String,
+}
+
+fuzz_target!(|input: Input| {
+    if computer::can_create_word(&input.rack, &input.word) {
+        // Every letter of the word uses up a tile
+        assert!(input.word.chars().count() <= input.rack.len());
+    }
+});
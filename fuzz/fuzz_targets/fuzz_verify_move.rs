@@ -0,0 +1,27 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use scrabby::{computer, Board, Direction, Position, Word};
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input {
+    /// Moves played on the board before verifying `word`
+    moves: Vec<Word>,
+    word: Word,
+}
+
+fuzz_target!(|input: Input| {
+    let mut board = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+    board.make_move(
+        Position::new(board.size(), 10, 10),
+        "RUST",
+        Direction::Right,
+    );
+    for word in &input.moves {
+        if computer::verify_move(&board, word, scrabby::DEFAULT_WORD_LIST) {
+            board.make_move(word.position, &word.word, word.direction);
+        }
+    }
+
+    computer::verify_move(&board, &input.word, scrabby::DEFAULT_WORD_LIST);
+});
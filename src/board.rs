@@ -1,4 +1,8 @@
 #![allow(dead_code)]
+use crate::layout::{
+    parse_multipliers, BoardLayout, LayoutSizeMismatch, SUPER_SCRABBLE_LETTER_MULT,
+    SUPER_SCRABBLE_WORD_MULT,
+};
 use crate::{computer, letter::Letter};
 use core::fmt;
 use std::borrow::Cow;
@@ -12,18 +16,73 @@ pub struct Board {
     inner: Vec<Option<Letter>>,
     moves: Vec<Word>,
     size: usize,
+    word_mult: Vec<u8>,
+    letter_mult: Vec<u8>,
+    /// Tiles replaced by tentative words, to be restored by [`Board::revert_tentative`]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tentative: Vec<(Position, Option<Letter>)>,
+    /// Where each tentative word starts in `tentative`, the token is its index
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tentative_starts: Vec<usize>,
 }
 
 impl Board {
     pub const DEFAULT_SS_BOARD_SIZE: usize = 21;
+
+    /**
+    Creates an empty board with the Super Scrabble premium squares.
+
+    Boards of other sizes take the top left corner of the Super Scrabble layout, with
+    normal squares past its edges. Use [`Board::new_from_size_and_layout`] to pick a
+    layout for them instead.
+    */
     pub fn new(size: usize) -> Board {
+        let crop = |grid: &str| {
+            let grid = parse_multipliers(grid);
+            let grid_size = Board::DEFAULT_SS_BOARD_SIZE;
+            (0..size * size)
+                .map(|i| {
+                    let (row, col) = (i / size, i % size);
+                    if row < grid_size && col < grid_size {
+                        grid[row * grid_size + col]
+                    } else {
+                        1
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let (word_mult, letter_mult) = (
+            crop(SUPER_SCRABBLE_WORD_MULT),
+            crop(SUPER_SCRABBLE_LETTER_MULT),
+        );
         Board {
             inner: vec![None; size * size],
             moves: Vec::new(),
             size,
+            word_mult,
+            letter_mult,
+            tentative: Vec::new(),
+            tentative_starts: Vec::new(),
         }
     }
 
+    /// Creates an empty board with the premium squares of `layout`.
+    pub fn new_from_size_and_layout(
+        size: usize,
+        layout: BoardLayout,
+    ) -> Result<Board, LayoutSizeMismatch> {
+        let (word_mult, letter_mult) = layout.multipliers(size)?;
+        Ok(Board {
+            inner: vec![None; size * size],
+            moves: Vec::new(),
+            size,
+            word_mult,
+            letter_mult,
+            tentative: Vec::new(),
+            tentative_starts: Vec::new(),
+        })
+    }
+
     pub fn size(&self) -> usize {
         self.size
     }
@@ -37,53 +96,247 @@ impl Board {
     }
 
     pub fn enumerate_letters(&self) -> impl Iterator<Item = (Position, Letter)> + '_ {
-        self.inner.iter().enumerate().filter_map(|x| {
-            x.1.map(|y| {
-                (
-                    Position {
-                        board_size: self.size,
-                        index: x.0,
-                    },
-                    y,
-                )
-            })
-        })
+        self.iter().filter_map(|(p, t)| t.map(|l| (p, l)))
+    }
+
+    /// Iterates over every square of the board in row-major order, including empty ones.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.inner.iter().enumerate(),
+            board_size: self.size,
+        }
     }
 
-    pub fn make_move(&mut self, mut position: Position, word: &str, direction: Direction) {
+    /// Plays `word` on the board, returning the changes made to the board.
+    pub fn make_move(
+        &mut self,
+        mut position: Position,
+        word: &str,
+        direction: Direction,
+    ) -> BoardDelta {
         let start = position;
+        let mut delta = BoardDelta::default();
         for char in word.chars() {
-            self.set(position, Some(Letter::from_char(char)));
+            delta = delta.then(self.set(position, Some(Letter::from_char(char))));
             position = position.add_direction(direction, 1);
         }
 
-        self.moves
-            .push(Word::new(start, direction, Cow::Borrowed(word).to_owned()));
+        let word = Word::new(start, direction, Cow::Borrowed(word));
+        self.moves.push(word.clone());
+        delta.moves.push(word);
+        delta
     }
 
     pub fn get(&self, position: Position) -> Option<Letter> {
         self.inner.get(position.index).cloned().flatten()
     }
 
-    pub fn set(&mut self, position: Position, letter: Option<Letter>) {
-        self.inner[position.index] = letter;
+    /**
+    Gets the tile at `position` without checking that it is on the board.
+
+    # Safety
+    `position` must be on this board, i.e. created with this board's size and
+    with an index less than `size * size`. Positions returned by
+    [`Position::try_add_direction`] from a position on the board always are.
+    */
+    pub unsafe fn get_unchecked(&self, position: Position) -> Option<Letter> {
+        debug_assert!(position.index < self.inner.len());
+        *self.inner.get_unchecked(position.index)
+    }
+
+    /**
+    Sets the tile at `position`, returning the changes made to the board.
+
+    Setting a tile to the letter already on it does nothing.
+
+    # Errors
+    * [`SetError::OutOfBounds`] if `position` isn't on the board
+    * [`SetError::TileConflict`] if there is already a different letter on the square
+    * [`SetError::PartOfRecordedMove`] if clearing a tile of one of the board's moves
+    */
+    pub fn set_with_validation(
+        &mut self,
+        position: Position,
+        letter: Option<Letter>,
+    ) -> Result<BoardDelta, SetError> {
+        if position.board_size != self.size || position.index >= self.size * self.size {
+            return Err(SetError::OutOfBounds);
+        }
+        match (self.inner[position.index], letter) {
+            (Some(old), Some(new)) if old != new => return Err(SetError::TileConflict),
+            (Some(_), None) if self.moves.iter().any(|x| x.covers(position)) => {
+                return Err(SetError::PartOfRecordedMove)
+            }
+            _ => {}
+        }
+        Ok(self.set(position, letter))
+    }
+
+    /// Sets the tile at `position`, returning the changes made to the board.
+    pub(crate) fn set(&mut self, position: Position, letter: Option<Letter>) -> BoardDelta {
+        let old = std::mem::replace(&mut self.inner[position.index], letter);
+        let mut delta = BoardDelta::default();
+        if old != letter {
+            delta.cells.push(CellChange {
+                position,
+                old,
+                new: letter,
+            });
+        }
+        delta
+    }
+
+    /**
+    Places the tiles of `word` on the board without recording it as a move,
+    returning a token to undo it with [`Board::revert_tentative`].
+
+    Tentative words can be nested, and reverting one also reverts every tentative
+    word applied after it. `word` must fit on the board.
+    */
+    pub fn apply_word_tentatively(&mut self, word: &Word) -> usize {
+        let token = self.tentative_starts.len();
+        self.tentative_starts.push(self.tentative.len());
+        for (i, char) in word.word.chars().enumerate() {
+            let position = word.position.add_direction(word.direction, i as isize);
+            let old = self.inner[position.index].replace(Letter::from_char(char));
+            self.tentative.push((position, old));
+        }
+        token
+    }
+
+    /// Removes the tiles placed by the tentative word `token`, and any applied after it.
+    pub fn revert_tentative(&mut self, token: usize) {
+        debug_assert!(
+            token < self.tentative_starts.len(),
+            "tentative words must be reverted in the reverse order they were applied"
+        );
+        let Some(&start) = self.tentative_starts.get(token) else {
+            return;
+        };
+        self.tentative_starts.truncate(token);
+        for (position, old) in self.tentative.drain(start..).rev() {
+            self.inner[position.index] = old;
+        }
+    }
+
+    /// Applies the changes in `delta`, which must have been made against a board
+    /// with the same tiles as this one.
+    pub fn apply_delta(&mut self, delta: &BoardDelta) {
+        for cell in &delta.cells {
+            self.inner[cell.position.index] = cell.new;
+        }
+        self.moves.extend(delta.moves.iter().cloned());
+    }
+
+    /// Returns the letter multiplier of the square at `position`.
+    pub fn letter_multiplier(&self, position: Position) -> u32 {
+        self.letter_mult.get(position.index).cloned().unwrap_or(1) as u32
+    }
+
+    /// Returns the word multiplier of the square at `position`.
+    pub fn word_multiplier(&self, position: Position) -> u32 {
+        self.word_mult.get(position.index).cloned().unwrap_or(1) as u32
+    }
+
+    /// Classifies the premium (if any) of the square at `position`.
+    pub fn square_type(&self, position: Position) -> SquareType {
+        if position.index == (self.size / 2) * self.size + self.size / 2 {
+            return SquareType::Center;
+        }
+        match (
+            self.word_multiplier(position),
+            self.letter_multiplier(position),
+        ) {
+            (4, _) => SquareType::QuadrupleWord,
+            (3, _) => SquareType::TripleWord,
+            (2, _) => SquareType::DoubleWord,
+            (_, 4) => SquareType::QuadrupleLetter,
+            (_, 3) => SquareType::TripleLetter,
+            (_, 2) => SquareType::DoubleLetter,
+            _ => SquareType::Normal,
+        }
+    }
+
+    /**
+    Returns the longest run of letters going in `direction` through `position`,
+    or `None` if it is shorter than two letters or `position` is for a board of a
+    different size.
+    */
+    pub fn word_at(&self, position: Position, direction: Direction) -> Option<Word> {
+        if position.board_size != self.size {
+            return None;
+        }
+        self.get(position)?;
+        let word = computer::find_boundary_word(
+            self,
+            &Word::new(position, direction, Cow::Borrowed("")),
+            0,
+            direction,
+        );
+        (word.word.len() >= 2).then_some(word)
+    }
+
+    /// Iterates over the rows of the board, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[Option<Letter>]> + '_ {
+        // An empty board has no rows, but `chunks` needs a non-zero size
+        self.inner.chunks(self.size.max(1))
+    }
+
+    /// Iterates over the columns of the board from left to right, each from top to bottom.
+    pub fn iter_columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = Option<Letter>> + '_> + '_ {
+        (0..self.size).map(move |column| self.inner[column..].iter().step_by(self.size).copied())
     }
 
     pub fn print(&self) {
-        for i in 0..self.size {
-            for l in self.inner[i * self.size..(i + 1) * self.size]
-                .iter()
-                .map(|x| match x {
-                    Some(y) => y.to_char(),
-                    None => '.',
-                })
-            {
+        for row in self.iter_rows() {
+            for l in row.iter().map(|x| match x {
+                Some(y) => y.to_char(),
+                None => '.',
+            }) {
                 print!("{} ", l);
             }
             println!();
         }
     }
 
+    /**
+    Formats every move played on the board, one per line, as
+    `Move N: WORD at (row, col) going across for S points`.
+
+    Scores are calculated by replaying the moves in order on an empty board,
+    so each move is scored against the board as it was when it was played.
+    */
+    pub fn format_moves_log(&self) -> String {
+        let mut replay = Board {
+            inner: vec![None; self.inner.len()],
+            moves: Vec::new(),
+            ..self.clone()
+        };
+        let mut log = String::new();
+        for (i, mov) in self.moves.iter().enumerate() {
+            let score = mov.get_score(&replay, None);
+            log += &format!(
+                "Move {}: {} at {} going {} for {} points\n",
+                i + 1,
+                mov.word,
+                mov.position,
+                match mov.direction {
+                    Direction::Right => "across",
+                    Direction::Down => "down",
+                },
+                score
+            );
+            replay.make_move(mov.position, &mov.word, mov.direction);
+        }
+        log
+    }
+
+    pub fn print_moves_log(&self) {
+        print!("{}", self.format_moves_log());
+    }
+
     #[cfg(feature = "pretty-print")]
     pub fn print_highlight(&self, highlight: &[(Letter, Position)]) {
         use colored::Colorize;
@@ -96,18 +349,167 @@ impl Board {
             } else if let Some(letter) = l {
                 print!("{} ", letter.to_char());
             } else {
-                print!(". ",);
+                let square = ".".to_string();
+                let square = match self.square_type(Position::new_unchecked(self.size, i)) {
+                    SquareType::Normal => square.normal(),
+                    SquareType::DoubleLetter => square.cyan(),
+                    SquareType::TripleLetter => square.blue(),
+                    SquareType::QuadrupleLetter => square.bright_blue(),
+                    SquareType::DoubleWord => square.magenta(),
+                    SquareType::TripleWord => square.yellow(),
+                    SquareType::QuadrupleWord => square.bright_yellow(),
+                    SquareType::Center => square.green(),
+                };
+                print!("{} ", square);
             }
         }
         println!();
     }
 }
 
+impl<'a> IntoIterator for &'a Board {
+    type Item = (Position, Option<Letter>);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over every square of a [`Board`], created by [`Board::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    inner: std::iter::Enumerate<std::slice::Iter<'a, Option<Letter>>>,
+    board_size: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = (Position, Option<Letter>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(index, letter)| (Position::new_unchecked(self.board_size, index), *letter))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+/// The reason [`Board::set_with_validation`] refused to set a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetError {
+    /// The position isn't on the board.
+    OutOfBounds,
+    /// There is already a different letter on the square.
+    TileConflict,
+    /// The tile being cleared is part of one of the board's moves.
+    PartOfRecordedMove,
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SetError::OutOfBounds => "position is not on the board",
+            SetError::TileConflict => "square already has a different letter",
+            SetError::PartOfRecordedMove => "tile is part of a move played on the board",
+        })
+    }
+}
+
+impl std::error::Error for SetError {}
+
+/// A single square changed on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct CellChange {
+    pub position: Position,
+    pub old: Option<Letter>,
+    pub new: Option<Letter>,
+}
+
+/**
+The changes made to a [`Board`] by a mutation, such as [`Board::make_move`].
+
+Applying a delta to a copy of the old board with [`Board::apply_delta`] gives the new board,
+so they can be sent to other copies of the board instead of the whole board.
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct BoardDelta {
+    /// Squares which changed, each position appearing at most once.
+    pub cells: Vec<CellChange>,
+    /// Moves added to the move history.
+    pub moves: Vec<Word>,
+}
+
+impl BoardDelta {
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty() && self.moves.is_empty()
+    }
+
+    /// Combines this delta with one made after it into a single delta.
+    pub fn then(mut self, next: BoardDelta) -> BoardDelta {
+        for change in next.cells {
+            match self
+                .cells
+                .iter()
+                .position(|x| x.position == change.position)
+            {
+                Some(i) if self.cells[i].old == change.new => {
+                    self.cells.remove(i);
+                }
+                Some(i) => self.cells[i].new = change.new,
+                None => self.cells.push(change),
+            }
+        }
+        self.moves.extend(next.moves);
+        self
+    }
+
+    /// The premium squares which were covered by a new tile.
+    pub fn premiums_consumed(&self, board: &Board) -> Vec<(Position, SquareType)> {
+        self.cells
+            .iter()
+            .filter(|x| x.old.is_none() && x.new.is_some())
+            .map(|x| (x.position, board.square_type(x.position)))
+            .filter(|x| x.1 != SquareType::Normal)
+            .collect()
+    }
+}
+
+/// The kind of premium a square on the board has.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
 )]
+pub enum SquareType {
+    Normal,
+    DoubleLetter,
+    TripleLetter,
+    QuadrupleLetter,
+    DoubleWord,
+    TripleWord,
+    QuadrupleWord,
+    Center,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Position {
     index: usize,
     board_size: usize,
@@ -121,18 +523,31 @@ impl Position {
         }
     }
 
+    /**
+    Creates a position from its index into the board, without checking that it is
+    on a board of size `board_size`.
+    */
+    pub const fn new_unchecked(board_size: usize, index: usize) -> Position {
+        Position { board_size, index }
+    }
+
+    #[deprecated(since = "0.1.0", note = "use Position::new_unchecked")]
     pub fn from_index(&self, board_size: usize, index: usize) -> Position {
         Position { board_size, index }
     }
 
     pub fn as_row_column(&self) -> (usize, usize) {
-        (self.index % self.board_size, self.index / self.board_size)
+        (self.index / self.board_size, self.index % self.board_size)
     }
 
     pub fn as_index(&self) -> usize {
         self.index
     }
 
+    pub fn board_size(&self) -> usize {
+        self.board_size
+    }
+
     pub fn add_row(self, amount: isize) -> Position {
         Position {
             board_size: self.board_size,
@@ -163,9 +578,8 @@ impl Position {
     pub fn try_add_direction(self, direction: Direction, amount: isize) -> Option<Position> {
         let new_index = self
             .index
-            .checked_add_signed(direction.offset(self.board_size) as isize * amount)
-            .unwrap();
-        if new_index > self.board_size.pow(2)
+            .checked_add_signed(direction.offset(self.board_size) as isize * amount)?;
+        if new_index >= self.board_size.pow(2)
             || (direction == Direction::Right
                 && self.index / self.board_size != new_index / self.board_size)
         {
@@ -191,6 +605,7 @@ impl fmt::Display for Position {
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Word {
     pub position: Position,
     pub direction: Direction,
@@ -211,6 +626,8 @@ impl Word {
     Accounts for letter and word multipliers
     Word extensions
 
+    Scores too large for a `u32`, which only custom layouts can make, are capped at `u32::MAX`.
+
     **TODO:**
     * Account for blank letters not having any score
     */
@@ -218,38 +635,106 @@ impl Word {
     // * Premiums will not be scored (except for the common letter)
     // * It will not branch into any new words
     pub fn get_score(&self, board: &Board, secondary_common_letter: Option<usize>) -> u32 {
-        let mut sum = 0;
-        // Contains letters from other words which are not scored with the word_mul or letter_mul
-        let mut post_sum = 0;
-        let mut word_mul = 1;
+        if let Some(secondary) = secondary_common_letter {
+            return self.score_with_premiums(board, |i| i == secondary);
+        }
 
-        for (i, char) in self.word.chars().enumerate() {
+        // Contains letters from other words which are not scored with the word_mul or letter_mul
+        let mut post_sum: u32 = 0;
+        for i in 0..self.word.len() {
             let location = self.position.add_direction(self.direction, i as isize);
-            let mut letter_mul = 1;
-            if secondary_common_letter.is_none()
-                || secondary_common_letter.is_some_and(|secondary| i == secondary)
-            {
-                letter_mul = crate::letter::LETTER_MULT
-                    .get(location.as_index())
-                    .cloned()
-                    .unwrap_or(1) as u32;
-                word_mul *= crate::letter::WORD_MULT
-                    .get(location.as_index())
-                    .cloned()
-                    .unwrap_or(1) as u32;
-            }
-            if secondary_common_letter.is_none() && board.get(location).is_none() {
+            if board.get(location).is_none() {
                 let boundary_word =
                     computer::find_boundary_word(board, self, i, self.direction.opposite());
                 let word_offset = (location.as_index() - boundary_word.position.as_index())
                     / boundary_word.direction.offset(board.size());
-                post_sum += boundary_word.get_score(board, Some(word_offset));
+                post_sum =
+                    post_sum.saturating_add(boundary_word.get_score(board, Some(word_offset)));
             }
-            sum += Letter::from_char(char).raw_score() as u32 * letter_mul;
         }
 
-        sum * word_mul + post_sum
+        self.score_without_crosswords(board)
+            .saturating_add(post_sum)
     }
+
+    /**
+    Gets the score of only this word on the board, with its letter and word multipliers,
+    ignoring any words it forms perpendicular to it.
+    */
+    pub fn score_without_crosswords(&self, board: &Board) -> u32 {
+        self.score_with_premiums(board, |_| true)
+    }
+
+    /**
+    Formats the word as a move in GCG notation, as `>player_name: WORD coordinate +score cumulative`.
+
+    The coordinate is the row number followed by the column letter for words going across
+    (e.g. `8D`), and the column letter followed by the row number for words going down (e.g. `D8`).
+    Boards wider than 26 columns continue with two letter columns (`AA`, `AB`, ...), and so on.
+
+    # Example
+    ```
+    use std::borrow::Cow;
+    use scrabby::{Board, Direction, Position, Word};
+
+    let position = Position::new(Board::DEFAULT_SS_BOARD_SIZE, 10, 10);
+    let word = Word::new(position, Direction::Right, Cow::Borrowed("RUST"));
+    assert_eq!(word.format_gcg("Alice", 8, 8), ">Alice: RUST 11K +8 8");
+    ```
+    */
+    pub fn format_gcg(&self, player_name: &str, score: u32, cumulative: u32) -> String {
+        let (row, column) = self.position.as_row_column();
+        let row = row + 1;
+        let column = gcg_column(column);
+        let coordinate = match self.direction {
+            Direction::Right => format!("{row}{column}"),
+            Direction::Down => format!("{column}{row}"),
+        };
+        format!(
+            ">{}: {} {} +{} {}",
+            player_name, self.word, coordinate, score, cumulative
+        )
+    }
+
+    /// Returns if one of the letters of the word is at `position`.
+    fn covers(&self, position: Position) -> bool {
+        (0..self.word.len()).any(|i| {
+            self.position
+                .try_add_direction(self.direction, i as isize)
+                .is_some_and(|x| x == position)
+        })
+    }
+
+    /// Scores the word, only applying the premiums of the letters where `premium` is true.
+    fn score_with_premiums(&self, board: &Board, premium: impl Fn(usize) -> bool) -> u32 {
+        let mut sum: u32 = 0;
+        let mut word_mul: u32 = 1;
+
+        for (i, char) in self.word.chars().enumerate() {
+            let location = self.position.add_direction(self.direction, i as isize);
+            let mut letter_mul = 1;
+            if premium(i) {
+                letter_mul = board.letter_multiplier(location);
+                word_mul = board.word_multiplier(location).saturating_mul(word_mul);
+            }
+            sum = sum.saturating_add(Letter::from_char(char).raw_score() as u32 * letter_mul);
+        }
+
+        sum.saturating_mul(word_mul)
+    }
+}
+
+/// Names the zero based `column` like spreadsheet columns: `A` to `Z`, then `AA`, `AB`, ...
+fn gcg_column(column: usize) -> String {
+    let mut name = Vec::new();
+    let mut column = column + 1;
+    while column > 0 {
+        column -= 1;
+        name.push(b'A' + (column % 26) as u8);
+        column /= 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -257,6 +742,7 @@ impl Word {
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Direction {
     Right,
     Down,
@@ -277,3 +763,488 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_row_column() {
+        let position = Position::new(Board::DEFAULT_SS_BOARD_SIZE, 3, 7);
+        assert_eq!(position.as_row_column(), (3, 7));
+        assert_eq!(position.to_string(), "(3, 7)");
+        assert_eq!(
+            Position::new_unchecked(Board::DEFAULT_SS_BOARD_SIZE, position.as_index()),
+            position
+        );
+    }
+
+    #[test]
+    fn square_types() {
+        let b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 10, 10)),
+            SquareType::Center
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 0)),
+            SquareType::QuadrupleWord
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 7)),
+            SquareType::TripleWord
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 1, 1)),
+            SquareType::DoubleWord
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 2, 5)),
+            SquareType::QuadrupleLetter
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 1, 4)),
+            SquareType::TripleLetter
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 3)),
+            SquareType::DoubleLetter
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 1)),
+            SquareType::Normal
+        );
+    }
+
+    #[test]
+    fn iterate_squares() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        assert_eq!(b.iter().len(), b.size() * b.size());
+
+        let mut letters = Vec::new();
+        for (position, tile) in &b {
+            if let Some(letter) = tile {
+                letters.push((position, letter));
+            }
+        }
+        assert_eq!(letters, b.enumerate_letters().collect::<Vec<_>>());
+        assert_eq!(
+            letters.first(),
+            Some(&(Position::new(b.size(), 10, 10), Letter::R))
+        );
+    }
+
+    #[test]
+    fn delta_applies() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        let old = b.clone();
+        let delta = b
+            .make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right)
+            .then(b.make_move(Position::new(b.size(), 10, 10), "RADICAL", Direction::Down))
+            .then(b.set(Position::new(b.size(), 0, 0), Some(Letter::Q)));
+        // The shared R is only changed once
+        assert_eq!(delta.cells.len(), 4 + 6 + 1);
+        assert_eq!(delta.moves, b.moves());
+        assert_eq!(
+            delta.premiums_consumed(&b)[0],
+            (Position::new(b.size(), 10, 10), SquareType::Center)
+        );
+
+        let mut copy = old.clone();
+        copy.apply_delta(&delta);
+        assert_eq!(
+            copy.iter().collect::<Vec<_>>(),
+            b.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(copy.moves(), b.moves());
+
+        // Undoing a change within the same delta cancels it out
+        let delta = b
+            .set(Position::new(b.size(), 0, 0), None)
+            .then(b.set(Position::new(b.size(), 0, 0), Some(Letter::Q)));
+        assert!(delta.is_empty());
+    }
+
+    #[test]
+    fn board_layouts() {
+        let b = Board::new_from_size_and_layout(15, BoardLayout::StandardScrabble).unwrap();
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 0)),
+            SquareType::TripleWord
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 1, 5)),
+            SquareType::TripleLetter
+        );
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 7, 7)),
+            SquareType::Center
+        );
+
+        let b = Board::new_from_size_and_layout(15, BoardLayout::WordsWithFriends).unwrap();
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 3)),
+            SquareType::TripleWord
+        );
+        assert_eq!(b.word_multiplier(Position::new(b.size(), 7, 7)), 1);
+
+        let b = Board::new_from_size_and_layout(21, BoardLayout::SuperScrabble).unwrap();
+        assert_eq!(
+            b.square_type(Position::new(b.size(), 0, 0)),
+            SquareType::QuadrupleWord
+        );
+
+        let b = Board::new_from_size_and_layout(
+            2,
+            BoardLayout::Custom {
+                word_mult: vec![1, 2, 1, 1],
+                letter_mult: vec![1, 1, 3, 1],
+            },
+        )
+        .unwrap();
+        assert_eq!(b.word_multiplier(Position::new(b.size(), 0, 1)), 2);
+        assert_eq!(b.letter_multiplier(Position::new(b.size(), 1, 0)), 3);
+    }
+
+    #[test]
+    fn default_layout_other_sizes() {
+        let b = Board::new(15);
+        assert_eq!(b.word_multiplier(Position::new(15, 0, 7)), 3);
+        assert_eq!(b.word_multiplier(Position::new(15, 1, 1)), 2);
+        assert_eq!(b.letter_multiplier(Position::new(15, 1, 4)), 3);
+        assert_eq!(b.word_multiplier(Position::new(15, 1, 14)), 1);
+
+        let b = Board::new(25);
+        assert_eq!(b.word_multiplier(Position::new(25, 20, 20)), 4);
+        assert_eq!(b.word_multiplier(Position::new(25, 20, 24)), 1);
+        assert_eq!(b.word_multiplier(Position::new(25, 24, 24)), 1);
+
+        assert_eq!(Board::new(0).iter().count(), 0);
+    }
+
+    #[test]
+    fn board_layout_score_overflow() {
+        let mut b = Board::new_from_size_and_layout(
+            Board::DEFAULT_SS_BOARD_SIZE,
+            BoardLayout::Custom {
+                word_mult: vec![255; 441],
+                letter_mult: vec![255; 441],
+            },
+        )
+        .unwrap();
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        let word = Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RADICAL"),
+        );
+        assert!(crate::computer::verify_move(
+            &b,
+            &word,
+            crate::DEFAULT_WORD_LIST
+        ));
+        assert_eq!(word.get_score(&b, None), u32::MAX);
+    }
+
+    #[test]
+    fn board_layout_size_mismatch() {
+        assert_eq!(
+            Board::new_from_size_and_layout(21, BoardLayout::StandardScrabble).unwrap_err(),
+            LayoutSizeMismatch {
+                expected: 441,
+                found: 225
+            }
+        );
+        assert!(Board::new_from_size_and_layout(
+            3,
+            BoardLayout::Custom {
+                word_mult: vec![1; 9],
+                letter_mult: vec![1; 4],
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        b.make_move(Position::new(b.size(), 10, 10), "RADICAL", Direction::Down);
+
+        assert_eq!(b.iter_rows().count(), b.size());
+        let row = b.iter_rows().nth(10).unwrap();
+        assert_eq!(row.len(), b.size());
+        assert_eq!(
+            row[10..14],
+            [
+                Some(Letter::R),
+                Some(Letter::U),
+                Some(Letter::S),
+                Some(Letter::T)
+            ]
+        );
+
+        assert_eq!(b.iter_columns().count(), b.size());
+        let column = b.iter_columns().nth(10).unwrap().collect::<Vec<_>>();
+        assert_eq!(column.len(), b.size());
+        assert_eq!(
+            column[10..17]
+                .iter()
+                .map(|x| x.unwrap().to_char())
+                .collect::<String>(),
+            "RADICAL"
+        );
+        assert!(b.iter_columns().nth(14).unwrap().all(|x| x.is_none()));
+
+        let b = Board::new(0);
+        assert_eq!(b.iter_rows().count(), 0);
+        assert_eq!(b.iter_columns().count(), 0);
+    }
+
+    #[test]
+    fn score_without_crosswords() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        let word = Word::new(
+            Position::new(b.size(), 11, 9),
+            Direction::Right,
+            Cow::Borrowed("TAP"),
+        );
+        // T and P are on double letters
+        assert_eq!(word.score_without_crosswords(&b), 2 + 1 + 3 * 2);
+        // RA and UP are also formed below RUST, where only the new letter gets its premium
+        assert_eq!(word.get_score(&b, None), 9 + (1 + 1) + (1 + 3 * 2));
+    }
+
+    #[test]
+    fn tentative_words() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        let old = b.iter().collect::<Vec<_>>();
+
+        let outer = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RADICAL"),
+        ));
+        let after_outer = b.iter().collect::<Vec<_>>();
+        let inner = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 16, 10),
+            Direction::Right,
+            Cow::Borrowed("LAD"),
+        ));
+        assert_eq!(b.get(Position::new(b.size(), 16, 12)), Some(Letter::D));
+        assert_eq!(b.moves().len(), 1);
+
+        b.revert_tentative(inner);
+        assert_eq!(b.iter().collect::<Vec<_>>(), after_outer);
+        b.revert_tentative(outer);
+        assert_eq!(b.iter().collect::<Vec<_>>(), old);
+    }
+
+    #[test]
+    fn tentative_empty_word() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        let empty = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Right,
+            Cow::Borrowed(""),
+        ));
+        let word = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Right,
+            Cow::Borrowed("AB"),
+        ));
+        assert_ne!(empty, word);
+
+        b.revert_tentative(word);
+        b.revert_tentative(empty);
+        assert_eq!(b.iter_letters().count(), 0);
+    }
+
+    #[test]
+    fn tentative_words_unwind() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        let outer = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Right,
+            Cow::Borrowed("RUST"),
+        ));
+        b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RADICAL"),
+        ));
+        // Reverting the outer word also reverts the inner one
+        b.revert_tentative(outer);
+        assert_eq!(b.iter_letters().count(), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "reverse order")]
+    fn tentative_words_out_of_order() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        let outer = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Right,
+            Cow::Borrowed("RUST"),
+        ));
+        let inner = b.apply_word_tentatively(&Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RADICAL"),
+        ));
+        b.revert_tentative(outer);
+        // Already reverted along with `outer`
+        b.revert_tentative(inner);
+    }
+
+    #[test]
+    fn validated_set() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+
+        assert_eq!(
+            b.set_with_validation(Position::new_unchecked(b.size(), 441), Some(Letter::A)),
+            Err(SetError::OutOfBounds)
+        );
+        assert_eq!(
+            b.set_with_validation(Position::new(b.size(), 10, 11), Some(Letter::A)),
+            Err(SetError::TileConflict)
+        );
+        assert_eq!(
+            b.set_with_validation(Position::new(b.size(), 10, 11), None),
+            Err(SetError::PartOfRecordedMove)
+        );
+        assert_eq!(
+            b.set_with_validation(Position::new(b.size(), 10, 11), Some(Letter::U)),
+            Ok(BoardDelta::default())
+        );
+
+        let position = Position::new(b.size(), 0, 0);
+        assert!(b
+            .set_with_validation(position, Some(Letter::Q))
+            .is_ok_and(|delta| delta.cells.len() == 1));
+        assert!(b.set_with_validation(position, None).is_ok());
+        assert_eq!(b.get(position), None);
+    }
+
+    #[test]
+    fn words_at_positions() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        b.make_move(Position::new(b.size(), 10, 10), "RADICAL", Direction::Down);
+
+        assert_eq!(
+            b.word_at(Position::new(b.size(), 10, 12), Direction::Right),
+            Some(Word::new(
+                Position::new(b.size(), 10, 10),
+                Direction::Right,
+                Cow::Borrowed("RUST")
+            ))
+        );
+        assert_eq!(
+            b.word_at(Position::new(b.size(), 16, 10), Direction::Down),
+            Some(Word::new(
+                Position::new(b.size(), 10, 10),
+                Direction::Down,
+                Cow::Borrowed("RADICAL")
+            ))
+        );
+        // A single letter isn't a word
+        assert_eq!(
+            b.word_at(Position::new(b.size(), 10, 12), Direction::Down),
+            None
+        );
+        assert_eq!(
+            b.word_at(Position::new(b.size(), 0, 0), Direction::Down),
+            None
+        );
+        assert_eq!(
+            b.word_at(Position::new_unchecked(15, 220), Direction::Right),
+            None
+        );
+    }
+
+    #[test]
+    fn gcg_moves() {
+        let b = Board::new_from_size_and_layout(15, BoardLayout::StandardScrabble).unwrap();
+        let word = Word::new(
+            Position::new(b.size(), 7, 3),
+            Direction::Right,
+            Cow::Borrowed("WORD"),
+        );
+        assert_eq!(word.format_gcg("Joel", 24, 24), ">Joel: WORD 8D +24 24");
+        let word = Word::new(
+            Position::new(b.size(), 0, 14),
+            Direction::Down,
+            Cow::Borrowed("QI"),
+        );
+        assert_eq!(word.format_gcg("Joel", 33, 57), ">Joel: QI O1 +33 57");
+
+        for (column, name) in [(25, "Z1"), (26, "AA1"), (27, "AB1"), (299, "KN1")] {
+            let word = Word::new(
+                Position::new(300, 0, column),
+                Direction::Down,
+                Cow::Borrowed("QI"),
+            );
+            assert_eq!(
+                word.format_gcg("Joel", 0, 0),
+                format!(">Joel: QI {name} +0 0")
+            );
+        }
+    }
+
+    #[test]
+    fn moves_log() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        // Off the diagonal, so a row and column mix up would show
+        b.make_move(Position::new(b.size(), 10, 13), "TAP", Direction::Down);
+        assert_eq!(
+            b.format_moves_log(),
+            "Move 1: RUST at (10, 10) going across for 8 points\n\
+             Move 2: TAP at (10, 13) going down for 5 points\n"
+        );
+    }
+
+    #[test]
+    fn try_add_direction_row_wrap() {
+        let size = Board::DEFAULT_SS_BOARD_SIZE;
+        assert_eq!(
+            Position::new(size, 4, 0).try_add_direction(Direction::Right, -1),
+            None
+        );
+        assert_eq!(
+            Position::new(size, 4, 20).try_add_direction(Direction::Right, 1),
+            None
+        );
+        assert_eq!(
+            Position::new(size, 4, 20).try_add_direction(Direction::Right, -20),
+            Some(Position::new(size, 4, 0))
+        );
+    }
+
+    #[test]
+    fn try_add_direction_board_edges() {
+        let size = Board::DEFAULT_SS_BOARD_SIZE;
+        assert_eq!(
+            Position::new(size, 0, 0).try_add_direction(Direction::Right, -1),
+            None
+        );
+        assert_eq!(
+            Position::new(size, 0, 5).try_add_direction(Direction::Down, -1),
+            None
+        );
+        assert_eq!(
+            Position::new(size, 20, 0).try_add_direction(Direction::Down, 1),
+            None
+        );
+        assert_eq!(
+            Position::new(size, 20, 20).try_add_direction(Direction::Right, 1),
+            None
+        );
+    }
+}
@@ -6,6 +6,7 @@ use crate::board::Position;
 use crate::board::Word;
 use crate::letter::Letter;
 use crate::letter::RackLetter;
+use crate::letter::TileRack;
 
 /**
 Returns an iterator over the best moves to play, with the moves
@@ -16,15 +17,23 @@ Move generation must be done beforehand so we can sort it by
 the word score. Since generating the score is relatively cheap,
 we can generate it even for the invalid moves, and prune them
 out later when we iterate through them.
+
+Moves with equal scores are ordered alphabetically by word, then by position index and
+direction, so the order is always the same for the same board, rack, and set of words.
+
+If the board is empty, the moves are the [`opening_moves`] instead.
 */
 pub fn best_moves<'a>(
     board: &'a Board,
     letters: &[RackLetter],
     word_list: &'a [&str],
 ) -> impl Iterator<Item = Word> + 'a {
-    let mut rack = Vec::from(letters);
-
     let mut best: Vec<(u32, Word)> = Vec::new();
+    if board.iter_letters().next().is_none() {
+        best = opening_candidates(board, letters, word_list);
+    }
+
+    let mut rack = Vec::from(letters);
     for (location, letter) in board.enumerate_letters() {
         rack.push(RackLetter::Letter(letter));
 
@@ -32,24 +41,429 @@ pub fn best_moves<'a>(
 
         for word in words {
             let move_positions = get_move_positions(board, location, word);
-            best.extend(move_positions.iter().map(|x| {
-                (
-                    x.get_score(board, None) + if rack.len() == 8 { 50 } else { 0 },
-                    (*x).clone(),
-                )
+            best.extend(move_positions.into_iter().filter_map(|x| {
+                // Words already on the board aren't moves
+                let placed = tiles_placed(board, &x);
+                (placed > 0).then(|| {
+                    let bonus = if placed == TileRack::MAX_SIZE { 50 } else { 0 };
+                    (x.get_score(board, None).saturating_add(bonus), x)
+                })
             }));
         }
 
         rack.pop();
     }
 
-    best.sort_unstable_by_key(|x| x.0);
+    sort_moves(&mut best);
+    best.into_iter()
+        .filter(move |m| verify_move(board, &m.1, word_list))
+        .map(move |m| m.1)
+}
+
+/**
+Returns an iterator over the best first moves on the empty `board`, scored with its premium
+squares, with the moves getting progressively weaker.
+
+Every word which can be made from `letters` is placed in both directions in every position
+which covers the center square. The moves are still checked against the tiles on `board`,
+so use [`best_moves`] once it isn't empty.
+*/
+pub fn opening_moves<'a>(
+    board: &'a Board,
+    letters: &[RackLetter],
+    word_list: &'a [&str],
+) -> impl Iterator<Item = Word> + 'a {
+    let mut best = opening_candidates(board, letters, word_list);
+    sort_moves(&mut best);
     best.into_iter()
-        .rev()
-        .filter(move |m| verify_move(&board, &m.1, word_list))
+        .filter(move |m| verify_move(board, &m.1, word_list))
         .map(move |m| m.1)
 }
 
+fn opening_candidates(
+    board: &Board,
+    letters: &[RackLetter],
+    word_list: &[&str],
+) -> Vec<(u32, Word)> {
+    let mut best = Vec::new();
+    for word in word_list
+        .iter()
+        .filter(|word| word.len() >= 2 && can_create_word(letters, word))
+    {
+        best.extend(opening_positions(board, word).map(|x| {
+            (
+                x.get_score(board, None)
+                    .saturating_add(if word.len() == TileRack::MAX_SIZE {
+                        50
+                    } else {
+                        0
+                    }),
+                x,
+            )
+        }));
+    }
+    best
+}
+
+/// Every position on an empty board where `word` covers the center square.
+fn opening_positions<'a>(board: &Board, word: &'a str) -> impl Iterator<Item = Word> + 'a {
+    let center = Position::new(board.size(), board.size() / 2, board.size() / 2);
+    [Direction::Down, Direction::Right]
+        .into_iter()
+        .flat_map(move |direction| {
+            (0..word.len()).filter_map(move |offset| {
+                center
+                    .try_add_direction(direction, -(offset as isize))
+                    .map(|position| Word::new(position, direction, Cow::Borrowed(word)))
+            })
+        })
+}
+
+fn sort_moves(best: &mut Vec<(u32, Word)>) {
+    // Equal scores are ordered by the move itself, so the output doesn't depend on the
+    // order of `word_list` or of the letters on the board
+    best.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| a.1.word.cmp(&b.1.word))
+            .then_with(|| a.1.position.as_index().cmp(&b.1.position.as_index()))
+            .then_with(|| a.1.direction.cmp(&b.1.direction))
+    });
+    // The same move is generated once for every board letter it crosses
+    best.dedup_by(|a, b| a.1 == b.1);
+}
+
+/// The value of keeping each letter, from A to Z, on the rack after a move.
+const LEAVE_VALUES: [f32; 26] = [
+    1.0, -2.0, 0.5, 0.0, 1.5, -2.0, -2.0, 0.5, -0.5, -3.0, -2.5, -0.5, 0.0, 0.0, -1.0, -0.5, -7.0,
+    1.0, 7.5, 0.0, -3.0, -5.0, -4.0, 3.0, -0.5, 2.0,
+];
+/// The value of keeping a blank on the rack after a move.
+const BLANK_LEAVE_VALUE: f32 = 25.0;
+/// The value of every extra copy of a letter kept on the rack.
+const DUPLICATE_LEAVE_VALUE: f32 = -3.0;
+/// The value of keeping a U alongside a Q, making up most of the Q's penalty.
+const QU_LEAVE_VALUE: f32 = 5.0;
+
+/**
+Estimates how good it is to keep the tiles `rack` after a move, in points.
+
+This uses a simplified leave table: blanks and S are worth keeping, awkward
+letters (such as a Q without a U) are not, and duplicate letters are penalised.
+*/
+pub fn rack_leave_value(rack: &[RackLetter]) -> f32 {
+    LetterCounts::new(rack).value()
+}
+
+/// The number of each letter, from A to Z, and of blanks on a rack.
+#[derive(Clone, Copy)]
+struct LetterCounts {
+    letters: [usize; 26],
+    blanks: usize,
+}
+
+impl LetterCounts {
+    fn new(rack: &[RackLetter]) -> LetterCounts {
+        let mut counts = LetterCounts {
+            letters: [0; 26],
+            blanks: 0,
+        };
+        for letter in rack {
+            match letter {
+                RackLetter::Blank => counts.blanks += 1,
+                RackLetter::Letter(letter) => counts.letters[letter_index(*letter)] += 1,
+            }
+        }
+        counts
+    }
+
+    /// See [`rack_leave_value`].
+    fn value(&self) -> f32 {
+        let mut value = self.blanks as f32 * BLANK_LEAVE_VALUE;
+        for (count, letter_value) in self.letters.iter().zip(LEAVE_VALUES) {
+            if *count > 0 {
+                value += *count as f32 * letter_value + (*count - 1) as f32 * DUPLICATE_LEAVE_VALUE;
+            }
+        }
+        if self.letters[letter_index(Letter::Q)] > 0 && self.letters[letter_index(Letter::U)] > 0 {
+            value += QU_LEAVE_VALUE;
+        }
+        value
+    }
+}
+
+fn letter_index(letter: Letter) -> usize {
+    (letter as u8 - b'A') as usize
+}
+
+/**
+Returns every valid position `word` can be played in using the tiles in `rack`,
+building off of the letters already on the board.
+
+Returns nothing if `word` has any characters which aren't letters.
+
+# Example
+```
+use scrabby::{
+    Board, Direction,
+    computer, letter::RackLetter, Position
+};
+
+let mut board = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+board.make_move(Position::new(board.size(), 10, 10), "RUST", Direction::Right);
+
+let rack = "AEZLTQU".chars().map(RackLetter::from_char).collect::<Vec<_>>();
+let positions = computer::find_playable_positions(&board, "QUETZAL", &rack, scrabby::DEFAULT_WORD_LIST);
+// Down through either the 'U' or the 'T' of "RUST"
+assert_eq!(positions.len(), 2);
+```
+*/
+pub fn find_playable_positions(
+    board: &Board,
+    word: &str,
+    rack: &[RackLetter],
+    word_list: &[&str],
+) -> Vec<Word> {
+    if !word.chars().all(|ch| Letter::try_from_char(ch).is_some()) {
+        return Vec::new();
+    }
+
+    let rack = TileRack::from(rack);
+    valid_positions(board, word, word_list)
+        .into_iter()
+        .filter(|x| rack.after_playing(x, board).is_some())
+        .collect()
+}
+
+/**
+Returns every valid position `word` can be played in on the board, along with its score,
+from highest to lowest scoring.
+
+Returns nothing if `word` isn't in `word_list`.
+*/
+pub fn moves_for_word(board: &Board, word: &str, word_list: &[&str]) -> Vec<(Word, u32)> {
+    if !word_list.contains(&word) {
+        return Vec::new();
+    }
+
+    let mut moves = valid_positions(board, word, word_list)
+        .into_iter()
+        .map(|x| {
+            let score = x.get_score(board, None);
+            (x, score)
+        })
+        .collect::<Vec<_>>();
+    moves.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    moves
+}
+
+/**
+Every position building off of a letter on the board that `word` can be played in.
+
+Positions where every letter of `word` is already on the board aren't moves, so they
+are skipped.
+*/
+fn valid_positions(board: &Board, word: &str, word_list: &[&str]) -> Vec<Word> {
+    let mut positions = board
+        .enumerate_letters()
+        .filter(|(_, letter)| word.contains(letter.to_char()))
+        .flat_map(|(location, _)| get_move_positions(board, location, word))
+        .filter(|x| is_legal_move(board, x, word_list))
+        .collect::<Vec<_>>();
+    positions.sort();
+    positions.dedup();
+    positions
+}
+
+/**
+Returns if `word` can be played as a turn on `board`.
+
+On top of [`verify_move`], the word has to place at least one tile, and has to either
+touch a tile already on the board, or cover the center square if the board is empty.
+*/
+pub(crate) fn is_legal_move(board: &Board, word: &Word, word_list: &[&str]) -> bool {
+    if !verify_move(board, word, word_list) || tiles_placed(board, word) == 0 {
+        return false;
+    }
+
+    let mut positions =
+        (0..word.word.len()).map(|i| word.position.add_direction(word.direction, i as isize));
+    if board.iter_letters().next().is_none() {
+        let center = Position::new(board.size(), board.size() / 2, board.size() / 2);
+        return positions.any(|x| x == center);
+    }
+    positions.any(|x| {
+        [Direction::Right, Direction::Down]
+            .into_iter()
+            .flat_map(|direction| [-1, 0, 1].map(|amount| x.try_add_direction(direction, amount)))
+            .flatten()
+            .any(|neighbour| board.get(neighbour).is_some())
+    })
+}
+
+/// The number of tiles `word` places on the board, skipping letters which are already on it.
+fn tiles_placed(board: &Board, word: &Word) -> usize {
+    (0..word.word.len())
+        .filter(|i| {
+            board
+                .get(word.position.add_direction(word.direction, *i as isize))
+                .is_none()
+        })
+        .count()
+}
+
+/**
+Returns if there is at least one valid move which can be played with `letters`.
+
+This is much cheaper than `best_moves(..).next().is_some()`, since moves are not
+scored or sorted, and the search stops at the first move which verifies.
+*/
+pub fn has_any_legal_move(board: &Board, letters: &[RackLetter], word_list: &[&str]) -> bool {
+    if board.iter_letters().next().is_none() {
+        return word_list
+            .iter()
+            .filter(|word| word.len() >= 2 && can_create_word(letters, word))
+            .any(|word| opening_positions(board, word).any(|x| verify_move(board, &x, word_list)));
+    }
+
+    // Every move uses one letter from the board, which can stand in for at most one
+    // missing tile, so most of the word list can be ruled out in a single pass
+    let mut rack = Vec::from(letters);
+    rack.push(RackLetter::Blank);
+    let candidates = word_list
+        .iter()
+        .filter(|word| word.len() >= 2 && can_create_word(&rack, word))
+        .collect::<Vec<_>>();
+
+    board.enumerate_letters().any(|(location, letter)| {
+        *rack.last_mut().unwrap() = RackLetter::Letter(letter);
+        candidates
+            .iter()
+            .filter(|word| can_create_word(&rack, word))
+            .any(|word| {
+                get_move_positions(board, location, word)
+                    .iter()
+                    .any(|x| is_legal_move(board, x, word_list))
+            })
+    })
+}
+
+/// The minimum number of tiles which must be left in the bag to exchange.
+pub const MIN_BAG_SIZE_FOR_EXCHANGE: usize = 7;
+
+/// The number of highest scoring placements [`recommend_turn`] compares by equity.
+const RECOMMEND_CANDIDATES: usize = 20;
+
+/// A full turn: placing a word, exchanging some tiles, or passing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub enum Move {
+    Place(Word),
+    /// Exchange these tiles from the rack for new ones from the bag.
+    Exchange(Vec<RackLetter>),
+    Pass,
+}
+
+/// The options [`recommend_turn`] weighed up when choosing a [`Move`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnRationale {
+    /// The placement with the highest equity, and its score.
+    pub top_placement: Option<(Word, u32)>,
+    pub placement_equity: Option<f32>,
+    /// The tiles to keep for the best exchange, if exchanging is allowed.
+    pub best_keep: Option<Vec<RackLetter>>,
+    pub exchange_equity: Option<f32>,
+    pub pass_equity: f32,
+}
+
+/**
+Recommends a full turn to play with the rack `letters`.
+
+Each option is given an equity, which is the points it scores plus the value of the
+tiles it leaves on the rack, as given by `leave_value`:
+* The highest scoring placements are compared by equity, with a 50 point bonus for
+  placing all [`TileRack::MAX_SIZE`] tiles
+* Exchanges are only considered if at least [`MIN_BAG_SIZE_FOR_EXCHANGE`] tiles are in
+  the bag and `letters` is a [valid](TileRack::is_valid) rack, in which case the best
+  set of tiles to keep is found
+* Passing keeps the whole rack
+
+On equal equity, placing is preferred over exchanging, and exchanging over passing.
+*/
+pub fn recommend_turn(
+    board: &Board,
+    letters: &[RackLetter],
+    bag_size: usize,
+    word_list: &[&str],
+    leave_value: impl Fn(&[RackLetter]) -> f32,
+) -> (Move, TurnRationale) {
+    let rack = TileRack::from(letters);
+
+    let mut top_placement: Option<(Word, u32, f32)> = None;
+    for x in best_moves(board, letters, word_list).take(RECOMMEND_CANDIDATES) {
+        let Some(leave) = rack.after_playing(&x, board) else {
+            continue;
+        };
+        let placed = rack.len() - leave.len();
+        let score = x
+            .get_score(board, None)
+            .saturating_add(if placed == TileRack::MAX_SIZE { 50 } else { 0 });
+        let equity = score as f32 + leave_value(&leave);
+        if top_placement.as_ref().is_none_or(|top| equity > top.2) {
+            top_placement = Some((x, score, equity));
+        }
+    }
+
+    let mut best_keep: Option<(Vec<RackLetter>, f32)> = None;
+    if bag_size >= MIN_BAG_SIZE_FOR_EXCHANGE && !letters.is_empty() && rack.is_valid() {
+        // Every subset of the rack, except for keeping all of it
+        for mask in 0..(1u32 << letters.len()) - 1 {
+            let keep = letters
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, x)| *x)
+                .collect::<Vec<_>>();
+            let equity = leave_value(&keep);
+            if best_keep.as_ref().is_none_or(|best| equity > best.1) {
+                best_keep = Some((keep, equity));
+            }
+        }
+    }
+
+    let pass_equity = leave_value(letters);
+
+    let mut choice = (Move::Pass, pass_equity);
+    if let Some((keep, equity)) = &best_keep {
+        if *equity >= choice.1 {
+            let mut exchange = Vec::from(letters);
+            for x in keep {
+                let index = exchange.iter().position(|y| y == x).unwrap();
+                exchange.remove(index);
+            }
+            choice = (Move::Exchange(exchange), *equity);
+        }
+    }
+    if let Some((x, _, equity)) = &top_placement {
+        if *equity >= choice.1 {
+            choice = (Move::Place(x.clone()), *equity);
+        }
+    }
+
+    (
+        choice.0,
+        TurnRationale {
+            placement_equity: top_placement.as_ref().map(|x| x.2),
+            top_placement: top_placement.map(|x| (x.0, x.1)),
+            exchange_equity: best_keep.as_ref().map(|x| x.1),
+            best_keep: best_keep.map(|x| x.0),
+            pass_equity,
+        },
+    )
+}
+
 /**
 Returns if you can create the word `word` using the letters in `rack`
 */
@@ -58,8 +472,11 @@ pub fn can_create_word(rack: &[RackLetter], word: &str) -> bool {
     let mut blank_count = rack.iter().filter(|&&x| x == RackLetter::Blank).count();
 
     'outer: for ch in word.chars() {
+        let Some(ch) = Letter::try_from_char(ch) else {
+            return false;
+        };
         for (i, letter) in rack.iter().enumerate() {
-            if *letter == RackLetter::Letter(Letter::from_char(ch)) {
+            if *letter == RackLetter::Letter(ch) {
                 rack[i] = RackLetter::Blank;
                 continue 'outer;
             }
@@ -107,8 +524,12 @@ pub fn get_move_positions(board: &Board, location: Position, word: &str) -> Vec<
             .filter(|x| Letter::from_char(*x.1 as char) == board.get(location).unwrap())
         {
             let string_position = letter.0;
-            let starting_position = location.add_direction(direction, -(string_position as isize));
-            good_ones.push(Word::new(starting_position, direction, Cow::Borrowed(word)));
+            // Words which would start off the board can never be played
+            if let Some(starting_position) =
+                location.try_add_direction(direction, -(string_position as isize))
+            {
+                good_ones.push(Word::new(starting_position, direction, Cow::Borrowed(word)));
+            }
         }
     }
 
@@ -126,11 +547,20 @@ Verify if a move is able to be played on the board.
 
 */
 pub fn verify_move(board: &Board, board_move: &Word, word_list: &[&str]) -> bool {
-    // Verify the word is in bounds
-    if board_move
-        .position
-        .try_add_direction(board_move.direction, board_move.word.len() as isize)
-        .is_none()
+    // Verify the move is for this board, and only has letters which can be played
+    if board_move.position.board_size() != board.size()
+        || !board_move.word.chars().all(|ch| ch.is_ascii_uppercase())
+    {
+        return false;
+    }
+
+    // Verify the word is in bounds, checking the position of the last letter
+    // so that words flush against the edge of the board are allowed
+    if board_move.word.is_empty()
+        || board_move
+            .position
+            .try_add_direction(board_move.direction, board_move.word.len() as isize - 1)
+            .is_none()
     {
         return false;
     }
@@ -138,13 +568,8 @@ pub fn verify_move(board: &Board, board_move: &Word, word_list: &[&str]) -> bool
     // Verify move extensions
     let new_word = find_boundary_word(board, board_move, 0, board_move.direction).word;
     if !new_word.is_empty()
-        && !(word_list.contains(&&*new_word)
-            || board
-                .moves()
-                .iter()
-                .map(|mov| &mov.word)
-                .find(|word| **word == new_word)
-                .is_some())
+        && !word_list.contains(&&*new_word)
+        && !board.moves().iter().any(|mov| mov.word == new_word)
     {
         return false;
     }
@@ -166,27 +591,83 @@ pub fn verify_move(board: &Board, board_move: &Word, word_list: &[&str]) -> bool
         let new_word =
             find_boundary_word(board, board_move, i, board_move.direction.opposite()).word;
         if !new_word.is_empty()
-            && !(word_list.contains(&&*new_word)
-                || board
-                    .moves()
-                    .iter()
-                    .map(|mov| &mov.word)
-                    .find(|word| **word == new_word)
-                    .is_some())
+            && !word_list.contains(&&*new_word)
+            && !board.moves().iter().any(|mov| mov.word == new_word)
         {
             return false;
         }
     }
 
-    return true;
+    true
 }
 
+/**
+Returns all the words formed perpendicular to `word` by the tiles it places on the board.
+
+Letters of `word` which are already on the board don't form new words, so they are skipped.
+Returns nothing if `word` is positioned for a board of a different size.
+
+# Example
+```
+use std::borrow::Cow;
+use scrabby::{
+    Board, Direction,
+    computer, Position, Word
+};
+
+let mut board = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+board.make_move(Position::new(board.size(), 10, 10), "RUST", Direction::Right);
+
+let word = Word::new(Position::new(board.size(), 11, 9), Direction::Right, Cow::Borrowed("TAP"));
+let cross_words = computer::get_all_cross_words(&board, &word);
+assert_eq!(cross_words.len(), 2);
+assert_eq!(cross_words[0].word, "RA");
+assert_eq!(cross_words[1].word, "UP");
+```
+*/
+pub fn get_all_cross_words(board: &Board, word: &Word) -> Vec<Word> {
+    let mut cross_words: Vec<Word> = Vec::new();
+    if word.position.board_size() != board.size() {
+        return cross_words;
+    }
+
+    for i in 0..word.word.len() {
+        if board
+            .get(word.position.add_direction(word.direction, i as isize))
+            .is_some()
+        {
+            continue;
+        }
+        let cross_word = find_boundary_word(board, word, i, word.direction.opposite());
+        if !cross_word.word.is_empty() && !cross_words.contains(&cross_word) {
+            cross_words.push(cross_word);
+        }
+    }
+    cross_words
+}
+
+/**
+Returns the run of letters going in `direction` through letter `word_offset` of `word`,
+as if `word` had been played on the board.
+
+The returned word is empty if there is no such run, or if `word` is positioned for a
+board of a different size.
+*/
 pub fn find_boundary_word(
     board: &Board,
     word: &Word,
     word_offset: usize,
     direction: Direction,
 ) -> Word {
+    // Positions are only checked against their own board size, so it must match to be able
+    // to look up the bounds without checking them
+    if word.position.board_size() != board.size() {
+        return Word {
+            position: word.position,
+            direction,
+            word: String::new(),
+        };
+    }
     let start = word
         .position
         .add_direction(word.direction, word_offset as isize);
@@ -198,7 +679,10 @@ pub fn find_boundary_word(
             let next = bound.try_add_direction(direction, if flip { -1 } else { 1 });
             match next {
                 None => break,
-                Some(next) if get_with_word(board, word, next).is_none() => break,
+                // SAFETY: `try_add_direction` only returns positions on the board
+                Some(next) if unsafe { get_with_word_unchecked(board, word, next) }.is_none() => {
+                    break
+                }
                 Some(next) => {
                     bound = next;
                 }
@@ -208,22 +692,36 @@ pub fn find_boundary_word(
     }
     let [start_bound, end_bound] = bounds;
     let mut boundary_word = String::new();
-    let mut i = start_bound;
     if start_bound != end_bound {
+        let mut i = start_bound;
         while i.as_index() <= end_bound.as_index() {
             boundary_word.push(get_with_word(board, word, i).unwrap().to_char());
             i = i.add_direction(direction, 1);
         }
     }
 
-    return Word {
+    Word {
         position: start_bound,
         direction,
         word: boundary_word,
-    };
+    }
 }
 
 fn get_with_word(board: &Board, word: &Word, position: Position) -> Option<Letter> {
+    get_from_word(board, word, position).or_else(|| board.get(position))
+}
+
+/// # Safety
+/// `position` must be on `board`.
+unsafe fn get_with_word_unchecked(
+    board: &Board,
+    word: &Word,
+    position: Position,
+) -> Option<Letter> {
+    get_from_word(board, word, position).or_else(|| board.get_unchecked(position))
+}
+
+fn get_from_word(board: &Board, word: &Word, position: Position) -> Option<Letter> {
     if position.as_index() >= word.position.as_index() {
         let (string_match, string_offset) = (
             (position.as_index() - word.position.as_index()) % word.direction.offset(board.size()),
@@ -236,7 +734,7 @@ fn get_with_word(board: &Board, word: &Word, position: Position) -> Option<Lette
         }
     }
 
-    board.get(position)
+    None
 }
 
 #[cfg(test)]
@@ -266,28 +764,25 @@ mod tests {
                     .map(|ch| RackLetter::Letter(Letter::from_char(ch)))
                     .collect::<Vec<_>>()
                     .as_slice(),
-                &crate::DEFAULT_WORD_LIST
+                crate::DEFAULT_WORD_LIST
             )
             .count(),
-            375
+            371
         );
     }
 
     #[test]
     fn perpendicular_verification() {
         let b = init_board();
-        assert_eq!(
-            computer::verify_move(
-                &b,
-                &Word::new(
-                    Position::new(b.size(), 11, 9),
-                    Direction::Right,
-                    Cow::Borrowed("RUST")
-                ),
-                &crate::DEFAULT_WORD_LIST
+        assert!(!computer::verify_move(
+            &b,
+            &Word::new(
+                Position::new(b.size(), 11, 9),
+                Direction::Right,
+                Cow::Borrowed("RUST")
             ),
-            false
-        );
+            crate::DEFAULT_WORD_LIST
+        ));
     }
 
     #[test]
@@ -304,4 +799,464 @@ mod tests {
             16
         );
     }
+
+    #[test]
+    fn boundary_word_left_edge() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        // Tiles at the end of the row above the vertical word must not be joined to it
+        b.make_move(Position::new(b.size(), 4, 19), "AT", Direction::Right);
+        let word = Word::new(
+            Position::new(b.size(), 5, 0),
+            Direction::Down,
+            Cow::Borrowed("SO"),
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 0, Direction::Right).word,
+            ""
+        );
+        assert!(computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+    }
+
+    #[test]
+    fn boundary_word_right_edge() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        // Tiles at the start of the row below the vertical word must not be joined to it
+        b.make_move(Position::new(b.size(), 6, 0), "AT", Direction::Right);
+        let word = Word::new(
+            Position::new(b.size(), 4, 20),
+            Direction::Down,
+            Cow::Borrowed("SO"),
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 1, Direction::Right).word,
+            ""
+        );
+        assert!(computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+    }
+
+    #[test]
+    fn boundary_word_top_edge() {
+        // Regression test: looking above the top row used to panic in `try_add_direction`
+        let b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        let word = Word::new(
+            Position::new(b.size(), 0, 0),
+            Direction::Down,
+            Cow::Borrowed("RUST"),
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 0, Direction::Right).word,
+            ""
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 0, Direction::Down).word,
+            "RUST"
+        );
+        assert!(computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+    }
+
+    #[test]
+    fn boundary_word_bottom_edge() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 17, 20), "RUST", Direction::Down);
+        let word = Word::new(
+            Position::new(b.size(), 20, 17),
+            Direction::Right,
+            Cow::Borrowed("TAT"),
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 0, Direction::Down).word,
+            ""
+        );
+        assert_eq!(
+            computer::find_boundary_word(&b, &word, 0, Direction::Right).word,
+            "TATT"
+        );
+        assert!(!computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+        assert!(computer::verify_move(
+            &b,
+            &Word::new(
+                Position::new(b.size(), 20, 18),
+                Direction::Right,
+                Cow::Borrowed("TA"),
+            ),
+            crate::DEFAULT_WORD_LIST
+        ));
+    }
+
+    #[test]
+    fn move_positions_near_edge() {
+        // Regression test: words starting off the board used to panic in `try_add_direction`
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 0, 0), "HELLO", Direction::Right);
+        // Orientations which would start off the board are skipped instead of panicking
+        assert!(computer::get_move_positions(&b, Position::new(b.size(), 0, 0), "OH").is_empty());
+        assert_eq!(
+            computer::get_move_positions(&b, Position::new(b.size(), 0, 0), "HA").len(),
+            2
+        );
+    }
+
+    #[test]
+    fn boundary_word_stays_in_row() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 4, 0), "AT", Direction::Right);
+        // An unverified move which runs off the end of the row onto the next one. Both
+        // bounds come from `try_add_direction`, so they are always on the same row
+        let word = Word::new(
+            Position::new(b.size(), 3, 18),
+            Direction::Right,
+            Cow::Borrowed("HELLO"),
+        );
+        let boundary_word = computer::find_boundary_word(&b, &word, 0, Direction::Right);
+        assert_eq!(boundary_word.word, "HEL");
+        assert_eq!(boundary_word.position, Position::new(b.size(), 3, 18));
+        assert!(!computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+    }
+
+    #[test]
+    fn any_legal_move() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        let rack = "ABCDEFG"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        assert!(computer::has_any_legal_move(
+            &b,
+            &rack,
+            crate::DEFAULT_WORD_LIST
+        ));
+    }
+
+    #[test]
+    fn no_legal_move_on_blocked_board() {
+        use crate::letter::RackLetter;
+
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "QI", Direction::Right);
+        let rack = "VVVVVVV"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        assert!(!computer::has_any_legal_move(
+            &b,
+            &rack,
+            crate::DEFAULT_WORD_LIST
+        ));
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    fn any_legal_move_matches_best_moves() {
+        use crate::letter::RackLetter;
+
+        for b in [init_board(), Board::new(Board::DEFAULT_SS_BOARD_SIZE)] {
+            for letters in ["QQQQQQQ", "VVVVVVV", "ZZ", "AEINRST", "Q"] {
+                let rack = letters
+                    .chars()
+                    .map(RackLetter::from_char)
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    computer::has_any_legal_move(&b, &rack, crate::DEFAULT_WORD_LIST),
+                    computer::best_moves(&b, &rack, crate::DEFAULT_WORD_LIST)
+                        .next()
+                        .is_some(),
+                    "{letters}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn word_list_order_independent() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        let rack = "AEDTSLI"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        let word_list = [
+            "AD", "AID", "AIDS", "AT", "DIAL", "EAST", "IDEAL", "LADS", "LEST", "RAID", "RATS",
+            "RUST", "RUSTED", "SAD", "SLAT", "STAR", "TALE", "TAR", "TEA", "TIDAL", "TRAIL",
+        ];
+        let mut shuffled = word_list;
+        shuffled.reverse();
+        shuffled.rotate_left(7);
+        assert_ne!(word_list, shuffled);
+
+        let moves = computer::best_moves(&b, &rack, &word_list).collect::<Vec<_>>();
+        assert!(!moves.is_empty());
+        assert_eq!(
+            moves,
+            computer::best_moves(&b, &rack, &shuffled).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn opening_moves() {
+        use crate::letter::RackLetter;
+        use crate::BoardLayout;
+
+        let rack = "RUSTAEI"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        for b in [
+            Board::new(Board::DEFAULT_SS_BOARD_SIZE),
+            Board::new_from_size_and_layout(15, BoardLayout::StandardScrabble).unwrap(),
+        ] {
+            let center = Position::new(b.size(), b.size() / 2, b.size() / 2);
+            let moves =
+                computer::best_moves(&b, &rack, crate::DEFAULT_WORD_LIST).collect::<Vec<_>>();
+            assert!(!moves.is_empty());
+            assert_eq!(
+                moves,
+                computer::opening_moves(&b, &rack, crate::DEFAULT_WORD_LIST).collect::<Vec<_>>()
+            );
+            assert!(moves.iter().all(|x| (0..x.word.len())
+                .any(|i| x.position.add_direction(x.direction, i as isize) == center)));
+            assert!(moves
+                .windows(2)
+                .all(|x| x[0].get_score(&b, None) >= x[1].get_score(&b, None)));
+        }
+    }
+
+    #[test]
+    fn recommend_placement() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        let rack = "ABCDEFG"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        let (turn, rationale) =
+            computer::recommend_turn(&b, &rack, 80, crate::DEFAULT_WORD_LIST, |_| 0.0);
+        let best = computer::best_moves(&b, &rack, crate::DEFAULT_WORD_LIST)
+            .next()
+            .unwrap();
+        assert_eq!(turn, computer::Move::Place(best.clone()));
+        assert_eq!(rationale.top_placement.map(|x| x.0), Some(best));
+        assert!(rationale.best_keep.is_some());
+    }
+
+    #[test]
+    fn recommend_exchange_or_pass() {
+        use crate::letter::RackLetter;
+
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "QI", Direction::Right);
+        let rack = "VVVVVVV"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        // Every V left on the rack is a penalty, and an E is a bonus
+        let leave_value = |leave: &[RackLetter]| {
+            leave
+                .iter()
+                .map(|&x| {
+                    if x == RackLetter::from_char('V') {
+                        -5.0
+                    } else {
+                        1.0
+                    }
+                })
+                .sum::<f32>()
+        };
+
+        let (turn, rationale) =
+            computer::recommend_turn(&b, &rack, 80, crate::DEFAULT_WORD_LIST, leave_value);
+        assert_eq!(turn, computer::Move::Exchange(rack.clone()));
+        assert_eq!(rationale.top_placement, None);
+        assert_eq!(rationale.best_keep, Some(Vec::new()));
+        assert_eq!(rationale.exchange_equity, Some(0.0));
+        assert_eq!(rationale.pass_equity, -35.0);
+
+        let (turn, rationale) =
+            computer::recommend_turn(&b, &rack, 3, crate::DEFAULT_WORD_LIST, leave_value);
+        assert_eq!(turn, computer::Move::Pass);
+        assert_eq!(rationale.best_keep, None);
+    }
+
+    #[test]
+    fn recommend_end_game() {
+        use crate::letter::{RackLetter, TileRack};
+
+        let b = init_board();
+        let rack = "ES"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<TileRack>();
+        let (_, rationale) =
+            computer::recommend_turn(&b, &rack, 0, crate::DEFAULT_WORD_LIST, |_| 0.0);
+        // Playing out the last few tiles isn't a bingo
+        let (x, score) = rationale.top_placement.unwrap();
+        assert_eq!(rack.after_playing(&x, &b).map(|x| x.len()), Some(0));
+        assert_eq!(score, x.get_score(&b, None));
+
+        // Too many tiles to be a rack, so exchanges aren't considered
+        let rack = [RackLetter::from_char('V'); 32];
+        let (_, rationale) =
+            computer::recommend_turn(&b, &rack, 80, crate::DEFAULT_WORD_LIST, |_| 0.0);
+        assert_eq!(rationale.best_keep, None);
+    }
+
+    #[test]
+    fn leave_values() {
+        use crate::letter::RackLetter;
+
+        let leave = |letters: &str| {
+            computer::rack_leave_value(
+                &letters
+                    .chars()
+                    .map(RackLetter::from_char)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert!(leave("S ") > leave("ER"));
+        assert!(leave("Q") < 0.0);
+        assert!(leave("QU") > leave("Q"));
+        assert!(leave("EE") < leave("E") * 2.0);
+        assert_eq!(leave(""), 0.0);
+    }
+
+    #[test]
+    fn playable_positions() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        let rack = "AEZLTQU"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        let positions =
+            computer::find_playable_positions(&b, "QUETZAL", &rack, crate::DEFAULT_WORD_LIST);
+        assert!(!positions.is_empty());
+        assert!(
+            computer::find_playable_positions(&b, "QUETZAl", &rack, crate::DEFAULT_WORD_LIST)
+                .is_empty()
+        );
+        // The RADICAL already on the board doesn't need any tiles, but isn't a move
+        assert!(
+            computer::find_playable_positions(&b, "RADICAL", &[], crate::DEFAULT_WORD_LIST)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn moves_for_word() {
+        let b = init_board();
+        let moves = computer::moves_for_word(&b, "QUARTZ", crate::DEFAULT_WORD_LIST);
+        assert!(!moves.is_empty());
+        assert!(moves.windows(2).all(|x| x[0].1 >= x[1].1));
+        for (x, score) in &moves {
+            assert!(computer::verify_move(&b, x, crate::DEFAULT_WORD_LIST));
+            assert_eq!(x.get_score(&b, None), *score);
+        }
+
+        assert!(computer::moves_for_word(&b, "QUARTZZ", crate::DEFAULT_WORD_LIST).is_empty());
+    }
+
+    #[test]
+    fn best_moves_skips_played_words() {
+        use crate::letter::RackLetter;
+
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        b.make_move(Position::new(b.size(), 10, 11), "US", Direction::Down);
+        let rack = "USTQQQQ"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        assert!(computer::best_moves(&b, &rack, crate::DEFAULT_WORD_LIST)
+            .all(|x| !b.moves().contains(&x)));
+
+        let (turn, _) = computer::recommend_turn(&b, &rack, 80, crate::DEFAULT_WORD_LIST, |_| 0.0);
+        if let computer::Move::Place(x) = turn {
+            assert!(!b.moves().contains(&x));
+        }
+    }
+
+    #[test]
+    fn moves_for_word_skips_played_words() {
+        let b = init_board();
+        for word in ["RUST", "RADICAL"] {
+            assert!(computer::moves_for_word(&b, word, crate::DEFAULT_WORD_LIST)
+                .iter()
+                .all(|(x, _)| !b.moves().contains(x)));
+        }
+    }
+
+    #[test]
+    fn best_moves_snapshot() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        let rack = "ABCDEFG"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<Vec<_>>();
+        // Moves with equal scores are in alphabetical order
+        let moves = computer::best_moves(&b, &rack, crate::DEFAULT_WORD_LIST)
+            .take(8)
+            .map(|x| {
+                let score = x.get_score(&b, None);
+                format!("{} {} {:?} {}", x.word, x.position, x.direction, score)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            moves,
+            [
+                "CUBED (9, 11) Down 29",
+                "BIFACE (13, 9) Right 26",
+                "CADGE (14, 10) Right 24",
+                "CAGED (14, 10) Right 24",
+                "CUBE (9, 11) Down 24",
+                "DAUBE (8, 11) Down 22",
+                "FADED (12, 8) Right 22",
+                "FADGED (12, 8) Right 22",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        use crate::letter::RackLetter;
+
+        let b = init_board();
+        for word in [
+            Word::new(
+                Position::new(b.size(), 11, 9),
+                Direction::Right,
+                Cow::Borrowed("tap"),
+            ),
+            Word::new(
+                Position::new(b.size() + 1, 11, 9),
+                Direction::Right,
+                Cow::Borrowed("TAP"),
+            ),
+            Word::new(
+                Position::new_unchecked(b.size(), usize::MAX),
+                Direction::Down,
+                Cow::Borrowed("TAP"),
+            ),
+        ] {
+            assert!(!computer::verify_move(&b, &word, crate::DEFAULT_WORD_LIST));
+        }
+
+        let rack = [RackLetter::Blank; 7];
+        assert!(!computer::can_create_word(&rack, "ta p"));
+
+        let word = Word::new(
+            Position::new_unchecked(15, 150),
+            Direction::Right,
+            Cow::Borrowed("TAP"),
+        );
+        assert!(computer::find_boundary_word(&b, &word, 0, Direction::Right)
+            .word
+            .is_empty());
+        assert!(computer::get_all_cross_words(&b, &word).is_empty());
+    }
 }
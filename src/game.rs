@@ -0,0 +1,246 @@
+use crate::board::{Board, Word};
+use crate::computer;
+use crate::letter::TileRack;
+
+/// The state of a game between several players taking turns on one board.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+pub struct GameState {
+    board: Board,
+    racks: Vec<TileRack>,
+    scores: Vec<u32>,
+    turn_number: usize,
+    scoreless_turns: usize,
+}
+
+impl GameState {
+    /// The number of scoreless turns in a row which ends the game.
+    pub const MAX_SCORELESS_TURNS: usize = 6;
+
+    /**
+    Starts a game on `board` with one player for every rack.
+
+    # Panics
+    If there are no racks.
+    */
+    pub fn new(board: Board, racks: Vec<TileRack>) -> GameState {
+        assert!(!racks.is_empty(), "a game needs at least one player");
+        GameState {
+            board,
+            scores: vec![0; racks.len()],
+            racks,
+            turn_number: 0,
+            scoreless_turns: 0,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn num_players(&self) -> usize {
+        self.racks.len()
+    }
+
+    /// The number of turns taken so far by all players, counting passes as well as plays.
+    pub fn turn_number(&self) -> usize {
+        self.turn_number
+    }
+
+    /// The index of the player whose turn it is.
+    pub fn current_player(&self) -> usize {
+        self.turn_number % self.racks.len()
+    }
+
+    pub fn current_player_rack(&self) -> &TileRack {
+        &self.racks[self.current_player()]
+    }
+
+    /// Returns if the current player has no legal move, and so has to pass or exchange.
+    pub fn must_pass(&self, word_list: &[&str]) -> bool {
+        !computer::has_any_legal_move(&self.board, self.current_player_rack(), word_list)
+    }
+
+    /// Returns if [`GameState::MAX_SCORELESS_TURNS`] turns in a row have scored nothing.
+    pub fn is_over(&self) -> bool {
+        self.scoreless_turns >= Self::MAX_SCORELESS_TURNS
+    }
+
+    /**
+    Returns the score of `player`.
+
+    # Panics
+    If there is no player with the index `player`.
+    */
+    pub fn player_score(&self, player: usize) -> u32 {
+        self.scores[player]
+    }
+
+    /**
+    Plays `word` for the current player, removing the tiles it uses from their rack,
+    and returns the score it made.
+
+    Returns `None`, leaving the game unchanged, if the current player doesn't have the
+    tiles for it, or it isn't a valid move according to [`computer::verify_move`]. Moves
+    which don't place any tiles, or which aren't connected to the tiles on the board (or
+    don't cover the center square on an empty board) are rejected too.
+    */
+    pub fn play_move(&mut self, word: &Word, word_list: &[&str]) -> Option<u32> {
+        if !computer::is_legal_move(&self.board, word, word_list) {
+            return None;
+        }
+
+        let player = self.current_player();
+        let rack = self.racks[player].after_playing(word, &self.board)?;
+        let score = word.get_score(&self.board, None);
+
+        self.board
+            .make_move(word.position, &word.word, word.direction);
+        self.racks[player] = rack;
+        self.scores[player] = self.scores[player].saturating_add(score);
+        self.end_turn(score);
+        Some(score)
+    }
+
+    /// Passes the current player's turn, which still counts towards [`GameState::turn_number`].
+    pub fn pass(&mut self) {
+        self.end_turn(0);
+    }
+
+    fn end_turn(&mut self, score: u32) {
+        self.turn_number += 1;
+        if score == 0 {
+            self.scoreless_turns += 1;
+        } else {
+            self.scoreless_turns = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::letter::RackLetter;
+    use crate::{Direction, Position};
+
+    fn rack(letters: &str) -> TileRack {
+        letters.chars().map(RackLetter::from_char).collect()
+    }
+
+    #[test]
+    fn turns() {
+        let mut game = GameState::new(
+            Board::new(Board::DEFAULT_SS_BOARD_SIZE),
+            vec![rack("RUSTEAP"), rack("ADICALE")],
+        );
+        assert_eq!(game.num_players(), 2);
+        assert_eq!(game.turn_number(), 0);
+        assert_eq!(game.current_player_rack(), &rack("RUSTEAP"));
+
+        let size = game.board().size();
+        let word = |row, col, direction, word| {
+            Word::new(
+                Position::new(size, row, col),
+                direction,
+                Cow::Borrowed(word),
+            )
+        };
+        // The first move has to cover the center square
+        assert_eq!(
+            game.play_move(
+                &word(2, 2, Direction::Right, "RUST"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        let score = game.play_move(
+            &word(10, 10, Direction::Right, "RUST"),
+            crate::DEFAULT_WORD_LIST,
+        );
+        assert_eq!(score, Some(8));
+        assert_eq!(game.player_score(0), 8);
+        assert_eq!(game.turn_number(), 1);
+        assert_eq!(game.current_player_rack(), &rack("ADICALE"));
+
+        // The second player has no S
+        assert_eq!(
+            game.play_move(
+                &word(10, 10, Direction::Down, "RADICALS"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        // Invalid moves are rejected instead of panicking
+        assert_eq!(
+            game.play_move(
+                &word(10, 10, Direction::Down, "radical"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        assert_eq!(
+            game.play_move(
+                &word(20, 10, Direction::Down, "RADICAL"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        // Moves have to place a tile, and be connected to the rest of the board
+        assert_eq!(
+            game.play_move(
+                &word(10, 10, Direction::Right, "RUST"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        assert_eq!(
+            game.play_move(
+                &word(2, 2, Direction::Down, "ACID"),
+                crate::DEFAULT_WORD_LIST
+            ),
+            None
+        );
+        assert_eq!(game.turn_number(), 1);
+
+        game.play_move(
+            &word(10, 10, Direction::Down, "RADICAL"),
+            crate::DEFAULT_WORD_LIST,
+        )
+        .unwrap();
+        assert_eq!(game.player_score(1), 26);
+        assert_eq!(game.current_player_rack(), &rack("EAP"));
+
+        game.pass();
+        assert_eq!(game.turn_number(), 3);
+        assert_eq!(game.current_player(), 1);
+        assert_eq!(game.current_player_rack(), &rack("E"));
+    }
+
+    #[test]
+    fn scoreless_turns() {
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "QI", Direction::Right);
+        let mut game = GameState::new(b, vec![rack("VVVVVVV"), rack("AT")]);
+        assert!(game.must_pass(crate::DEFAULT_WORD_LIST));
+        game.pass();
+        assert!(!game.must_pass(crate::DEFAULT_WORD_LIST));
+
+        for _ in 1..GameState::MAX_SCORELESS_TURNS {
+            assert!(!game.is_over());
+            game.pass();
+        }
+        assert!(game.is_over());
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_player_score() {
+        let game = GameState::new(Board::new(Board::DEFAULT_SS_BOARD_SIZE), vec![rack("A")]);
+        game.player_score(1);
+    }
+}
@@ -0,0 +1,210 @@
+use core::fmt;
+
+/// The premium squares of a board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BoardLayout {
+    /// The 15x15 standard Scrabble board.
+    StandardScrabble,
+    /// The 21x21 Super Scrabble board.
+    SuperScrabble,
+    /// The 15x15 Words With Friends board.
+    WordsWithFriends,
+    /// Word and letter multipliers for every square, in row-major order.
+    Custom {
+        word_mult: Vec<u8>,
+        letter_mult: Vec<u8>,
+    },
+}
+
+impl BoardLayout {
+    /**
+    Returns the word and letter multipliers of every square on a `size` by `size` board.
+
+    Fails if the layout is for a different size of board.
+    */
+    pub fn multipliers(&self, size: usize) -> Result<(Vec<u8>, Vec<u8>), LayoutSizeMismatch> {
+        let (word_mult, letter_mult) = match self {
+            BoardLayout::StandardScrabble => (
+                parse_multipliers(STANDARD_WORD_MULT),
+                parse_multipliers(STANDARD_LETTER_MULT),
+            ),
+            BoardLayout::SuperScrabble => (
+                parse_multipliers(SUPER_SCRABBLE_WORD_MULT),
+                parse_multipliers(SUPER_SCRABBLE_LETTER_MULT),
+            ),
+            BoardLayout::WordsWithFriends => (
+                parse_multipliers(WORDS_WITH_FRIENDS_WORD_MULT),
+                parse_multipliers(WORDS_WITH_FRIENDS_LETTER_MULT),
+            ),
+            BoardLayout::Custom {
+                word_mult,
+                letter_mult,
+            } => (word_mult.clone(), letter_mult.clone()),
+        };
+
+        for found in [word_mult.len(), letter_mult.len()] {
+            if found != size * size {
+                return Err(LayoutSizeMismatch {
+                    expected: size * size,
+                    found,
+                });
+            }
+        }
+        Ok((word_mult, letter_mult))
+    }
+}
+
+/// A [`BoardLayout`] doesn't have the same number of squares as the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutSizeMismatch {
+    /// The number of squares on the board.
+    pub expected: usize,
+    /// The number of squares in the layout.
+    pub found: usize,
+}
+
+impl fmt::Display for LayoutSizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "layout has {} squares, but the board has {}",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for LayoutSizeMismatch {}
+
+/// Parses a grid of multipliers, where `.` is a multiplier of 1 and whitespace is ignored.
+pub(crate) fn parse_multipliers(grid: &str) -> Vec<u8> {
+    grid.as_bytes()
+        .iter()
+        .filter(|&&x| x != b'\n' && x != b'\r' && x != b' ')
+        .map(|&c| if c == b'.' { 1 } else { c - b'0' })
+        .collect()
+}
+
+pub(crate) const SUPER_SCRABBLE_WORD_MULT: &str = "
+    4......3.....3......4
+    .2......2...2......2.
+    ..2......2.2......2..
+    ...3......3......3...
+    ....2...........2....
+    .....2.........2.....
+    ......2.......2......
+    3......2.....2......3
+    .2.................2.
+    ..2...............2..
+    ...3......2......3...
+    ..2...............2..
+    .2.................2.
+    3......2.....2......3
+    ......2.......2......
+    .....2.........2.....
+    ....2...........2....
+    ...3......3......3...
+    ..2......2.2......2..
+    .2......2...2......2.
+    4......3.....3......4
+";
+
+pub(crate) const SUPER_SCRABBLE_LETTER_MULT: &str = "
+    ...2......2......2...
+    ....3...........3....
+    .....4.........4.....
+    2.....2.......2.....2
+    .3......3...3......3.
+    ..4......2.2......4..
+    ...2......2......2...
+    .....................
+    ....3...3...3...3....
+    .....2...2.2...2.....
+    2.....2.......2.....2
+    .....2...2.2...2.....
+    ....3...3...3...3....
+    .....................
+    ...2......2......2...
+    ..4......2.2......4..
+    .3......3...3......3.
+    2.....2.......2.....2
+    .....4.........4.....
+    ....3...........3....
+    ...2......2......2...
+";
+
+const STANDARD_WORD_MULT: &str = "
+    3......3......3
+    .2...........2.
+    ..2.........2..
+    ...2.......2...
+    ....2.....2....
+    ...............
+    ...............
+    3......2......3
+    ...............
+    ...............
+    ....2.....2....
+    ...2.......2...
+    ..2.........2..
+    .2...........2.
+    3......3......3
+";
+
+const STANDARD_LETTER_MULT: &str = "
+    ...2.......2...
+    .....3...3.....
+    ......2.2......
+    2......2......2
+    ...............
+    .3...3...3...3.
+    ..2...2.2...2..
+    ...2.......2...
+    ..2...2.2...2..
+    .3...3...3...3.
+    ...............
+    2......2......2
+    ......2.2......
+    .....3...3.....
+    ...2.......2...
+";
+
+const WORDS_WITH_FRIENDS_WORD_MULT: &str = "
+    ...3.......3...
+    .....2...2.....
+    ...............
+    3......2......3
+    ...............
+    .2...........2.
+    ...............
+    ...2.......2...
+    ...............
+    .2...........2.
+    ...............
+    3......2......3
+    ...............
+    .....2...2.....
+    ...3.......3...
+";
+
+const WORDS_WITH_FRIENDS_LETTER_MULT: &str = "
+    ......3.3......
+    ..2.........2..
+    .2..2.....2..2.
+    ...3.......3...
+    ..2...2.2...2..
+    .....3...3.....
+    3...2.....2...3
+    ...............
+    3...2.....2...3
+    .....3...3.....
+    ..2...2.2...2..
+    ...3.......3...
+    .2..2.....2..2.
+    ..2.........2..
+    ......3.3......
+";
@@ -1,58 +1,22 @@
-lazy_static::lazy_static! {
-    pub static ref WORD_MULT: &'static [u8] = Box::leak("
-        4......3.....3......4
-        .2......2...2......2.
-        ..2......2.2......2..
-        ...3......3......3...
-        ....2...........2....
-        .....2.........2.....
-        ......2.......2......
-        3......2.....2......3
-        .2.................2.
-        ..2...............2..
-        ...3......2......3...
-        ..2...............2..
-        .2.................2.
-        3......2.....2......3
-        ......2.......2......
-        .....2.........2.....
-        ....2...........2....
-        ...3......3......3...
-        ..2......2.2......2..
-        .2......2...2......2.
-        4......3.....3......4
-    ".as_bytes().iter().filter(|&&x| x != b'\n' && x != b'\r' && x != b' ').map(|&c| if c == b'.' {1} else {c - b'0'}).collect::<Vec<u8>>().into_boxed_slice());
-
-    pub static ref LETTER_MULT: &'static [u8] = Box::leak("
-        ...2......2......2...
-        ....3...........3....
-        .....4.........4.....
-        2.....2.......2.....2
-        .3......3...3......3.
-        ..4......2.2......4..
-        ...2......2......2...
-        .....................
-        ....3...3...3...3....
-        .....2...2.2...2.....
-        2.....2.......2.....2
-        .....2...2.2...2.....
-        ....3...3...3...3....
-        .....................
-        ...2......2......2...
-        ..4......2.2......4..
-        .3......3...3......3.
-        2.....2.......2.....2
-        .....4.........4.....
-        ....3...........3....
-        ...2......2......2...
-    ".as_bytes().iter().filter(|&&x| x != b'\n' && x != b'\r' && x != b' ').map(|&c| if c == b'.' {1} else {c - b'0'}).collect::<Vec<u8>>().into_boxed_slice());
-}
+use std::sync::LazyLock;
+
+use crate::board::{Board, Word};
+use crate::layout::{parse_multipliers, SUPER_SCRABBLE_LETTER_MULT, SUPER_SCRABBLE_WORD_MULT};
+
+#[deprecated(note = "use `Board::square_type` or `Board::word_multiplier` instead")]
+pub static WORD_MULT: LazyLock<&'static [u8]> =
+    LazyLock::new(|| Box::leak(parse_multipliers(SUPER_SCRABBLE_WORD_MULT).into_boxed_slice()));
+
+#[deprecated(note = "use `Board::square_type` or `Board::letter_multiplier` instead")]
+pub static LETTER_MULT: LazyLock<&'static [u8]> =
+    LazyLock::new(|| Box::leak(parse_multipliers(SUPER_SCRABBLE_LETTER_MULT).into_boxed_slice()));
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum RackLetter {
     Letter(Letter),
     Blank,
@@ -74,11 +38,113 @@ impl RackLetter {
     }
 }
 
+/// The tiles a player has available to play.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct TileRack {
+    letters: Vec<RackLetter>,
+}
+
+impl TileRack {
+    /// The most tiles a rack can hold.
+    pub const MAX_SIZE: usize = 7;
+
+    pub fn new(letters: Vec<RackLetter>) -> TileRack {
+        TileRack { letters }
+    }
+
+    /**
+    Returns if the rack holds no more than [`TileRack::MAX_SIZE`] tiles.
+
+    Every [`RackLetter`] is a valid tile, so only the size needs to be checked.
+    */
+    pub fn is_valid(&self) -> bool {
+        self.letters.len() <= Self::MAX_SIZE
+    }
+
+    /// Returns if `word` can be made using only the tiles on the rack.
+    pub fn can_create_word(&self, word: &str) -> bool {
+        crate::computer::can_create_word(&self.letters, word)
+    }
+
+    /// Alias of [`TileRack::can_create_word`].
+    pub fn can_play_word(&self, word: &str) -> bool {
+        self.can_create_word(word)
+    }
+
+    /**
+    Returns the rack left after playing `word` on `board`, or `None` if the rack
+    doesn't have the tiles for it.
+
+    Letters of `word` which are already on the board don't use a tile, and blanks
+    are only used when the letter itself isn't on the rack.
+    */
+    pub fn after_playing(&self, word: &Word, board: &Board) -> Option<TileRack> {
+        let mut letters = self.letters.clone();
+        for (i, ch) in word.word.chars().enumerate() {
+            if board
+                .get(word.position.add_direction(word.direction, i as isize))
+                .is_some()
+            {
+                continue;
+            }
+            let letter = RackLetter::Letter(Letter::from_char(ch));
+            let index = letters
+                .iter()
+                .position(|x| *x == letter)
+                .or_else(|| letters.iter().position(|x| *x == RackLetter::Blank))?;
+            letters.remove(index);
+        }
+        Some(TileRack { letters })
+    }
+}
+
+impl core::ops::Deref for TileRack {
+    type Target = [RackLetter];
+
+    fn deref(&self) -> &[RackLetter] {
+        &self.letters
+    }
+}
+
+impl From<Vec<RackLetter>> for TileRack {
+    fn from(letters: Vec<RackLetter>) -> TileRack {
+        TileRack { letters }
+    }
+}
+
+impl From<&[RackLetter]> for TileRack {
+    fn from(letters: &[RackLetter]) -> TileRack {
+        TileRack {
+            letters: Vec::from(letters),
+        }
+    }
+}
+
+impl FromIterator<RackLetter> for TileRack {
+    fn from_iter<T: IntoIterator<Item = RackLetter>>(iter: T) -> TileRack {
+        TileRack {
+            letters: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Each letter followed by its score in subscript numerals, from A to Z.
+const SUBSCRIPT_SCORE_STRS: [&str; 26] = [
+    "A₁", "B₃", "C₃", "D₂", "E₁", "F₄", "G₂", "H₄", "I₁", "J₈", "K₅", "L₁", "M₃", "N₁", "O₁", "P₃",
+    "Q₁₀", "R₁", "S₁", "T₁", "U₁", "V₄", "W₄", "X₈", "Y₄", "Z₁₀",
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(
     feature = "serde",
     derive(serde_derive::Serialize, serde_derive::Deserialize)
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[allow(unused)]
 #[repr(u8)]
 pub enum Letter {
@@ -112,18 +178,25 @@ pub enum Letter {
 
 impl Letter {
     pub fn from_char(c: char) -> Letter {
-        match c {
-            _ => {
-                assert!(c.is_ascii_uppercase());
-                unsafe { core::mem::transmute(c as u8) }
-            }
+        Letter::try_from_char(c).unwrap()
+    }
+
+    /// Returns the letter for `c`, or `None` if it isn't an uppercase ASCII letter.
+    pub fn try_from_char(c: char) -> Option<Letter> {
+        if c.is_ascii_uppercase() {
+            Some(unsafe { core::mem::transmute::<u8, Letter>(c as u8) })
+        } else {
+            None
         }
     }
 
     pub fn to_char(self) -> char {
-        match self {
-            _ => self as u8 as char,
-        }
+        self as u8 as char
+    }
+
+    /// Returns the letter with its score in subscript numerals, such as `"Q₁₀"`.
+    pub fn to_subscript_score_str(self) -> &'static str {
+        SUBSCRIPT_SCORE_STRS[(self as u8 - b'A') as usize]
     }
 
     pub fn raw_score(self) -> u8 {
@@ -167,8 +240,74 @@ mod tests {
         assert_eq!(Letter::from_char('J'), Letter::J);
     }
 
+    #[test]
+    pub fn letter_try_from_char() {
+        assert_eq!(Letter::try_from_char('J'), Some(Letter::J));
+        assert_eq!(Letter::try_from_char('j'), None);
+        assert_eq!(Letter::try_from_char('é'), None);
+    }
+
     #[test]
     pub fn char_from_letter() {
         assert_eq!(Letter::H.to_char(), 'H');
     }
+
+    #[test]
+    pub fn subscript_score_str() {
+        assert_eq!(Letter::Q.to_subscript_score_str(), "Q₁₀");
+        for c in 'A'..='Z' {
+            let letter = Letter::from_char(c);
+            let mut chars = letter.to_subscript_score_str().chars();
+            assert_eq!(chars.next(), Some(c));
+            let score = chars.fold(0, |score, x| score * 10 + (x as u32 - '₀' as u32));
+            assert_eq!(score, letter.raw_score() as u32);
+        }
+    }
+
+    #[test]
+    pub fn rack_validity() {
+        let rack = "ABCDEFG"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<TileRack>();
+        assert!(rack.is_valid());
+        let rack = "ABCDEFGH"
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<TileRack>();
+        assert!(!rack.is_valid());
+        assert!(rack.can_play_word("BEACH"));
+        assert!(!rack.can_play_word("BEACHES"));
+    }
+
+    #[test]
+    pub fn rack_after_playing() {
+        use crate::{Direction, Position};
+        use std::borrow::Cow;
+
+        let mut b = Board::new(Board::DEFAULT_SS_BOARD_SIZE);
+        b.make_move(Position::new(b.size(), 10, 10), "RUST", Direction::Right);
+        let rack = "TAPE P "
+            .chars()
+            .map(RackLetter::from_char)
+            .collect::<TileRack>();
+
+        // The R is already on the board, and the second T and the L come from blanks
+        let word = Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RATTLE"),
+        );
+        assert_eq!(
+            rack.after_playing(&word, &b),
+            Some("PP".chars().map(RackLetter::from_char).collect())
+        );
+
+        let word = Word::new(
+            Position::new(b.size(), 10, 10),
+            Direction::Down,
+            Cow::Borrowed("RATTLER"),
+        );
+        assert_eq!(rack.after_playing(&word, &b), None);
+    }
 }
@@ -1,8 +1,32 @@
 pub mod board;
 pub mod computer;
+pub mod game;
+pub mod layout;
 pub mod letter;
 
-pub use board::{Board, Direction, Position, Word};
+pub use board::{Board, BoardDelta, Direction, Position, SetError, SquareType, Word};
+pub use game::GameState;
+pub use layout::{BoardLayout, LayoutSizeMismatch};
 pub use letter::Letter;
 
 pub const DEFAULT_WORD_LIST: &[&str] = &include!(concat!(env!("OUT_DIR"), "/words.rs"));
+
+// Fails to compile if any of the public types stop being usable across threads
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_clone<T: Clone>() {}
+
+    fn assert_all() {
+        assert_send_sync::<Board>();
+        assert_send_sync::<BoardDelta>();
+        assert_send_sync::<Word>();
+        assert_send_sync::<Position>();
+        assert_send_sync::<Direction>();
+        assert_send_sync::<Letter>();
+        assert_send_sync::<letter::RackLetter>();
+        assert_send_sync::<computer::Move>();
+        assert_send_sync::<GameState>();
+        assert_clone::<Word>();
+    }
+};